@@ -14,7 +14,7 @@ async fn main() -> Result<(), Error> {
 
     println!("Найдено кампусов: {}", campuses.len());
     for campus in &campuses {
-        println!("  • {} (ID: {})", campus.name, campus.id);
+        println!("  • {}", campus);
     }
 
     if let Some(campus) = campuses.first() {
@@ -22,21 +22,22 @@ async fn main() -> Result<(), Error> {
 
         println!("\nНайдено групп: {}", groups.len());
         for group in &groups {
-            println!("  • {} (ID: {})", group.name, group.id);
+            println!("  • {}", group);
         }
 
         if let Some(group) = groups.first() {
-            let today = client.today(group.id).send().await?;
-
             println!("\nРасписание на сегодня для {}:", group.name);
-            for schedule in today {
-                println!("  📅 {}: {} занятий", schedule.date, schedule.lessons.len());
-                for lesson in schedule.lessons {
-                    println!(
-                        "    • {} — {} | {}",
-                        lesson.start_time, lesson.end_time, lesson.title
-                    );
+            match client.today(group.id).send_one().await? {
+                Some(schedule) => {
+                    println!("  📅 {}: {} занятий", schedule.date, schedule.lessons.len());
+                    for lesson in schedule.lessons {
+                        println!(
+                            "    • {} — {} | {}",
+                            lesson.start_time, lesson.end_time, lesson.title
+                        );
+                    }
                 }
+                None => println!("  Расписания нет"),
             }
         }
     }
@@ -15,18 +15,12 @@ impl AdminApi {
         request: CreateParserRequest,
     ) -> Result<CreateParserResponse> {
         let path = "/admin/parser";
-        self.client
-            .client
-            .post_json(path, Some(&request), Some(&self.client.auth))
-            .await
+        self.client.post_json(path, Some(&request)).await
     }
 
     /// Delete a parser
     pub async fn delete_parser(&self, parser_id: u32) -> Result<()> {
         let path = format!("/admin/parser/{}", parser_id);
-        self.client
-            .client
-            .delete_json(&path, Some(&self.client.auth))
-            .await
+        self.client.delete_json(&path).await
     }
 }
@@ -0,0 +1,1177 @@
+use crate::models::Week;
+use crate::{
+    Call, Campus, CampusesQuery, Client, College, CollegeQuery, Group, Lesson, Schedule,
+    TimedLesson, error::Error, error::Result,
+};
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc};
+use chrono_tz::Tz;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use tokio_util::sync::CancellationToken;
+
+/// Number of requests a fan-out helper keeps in flight at once.
+pub(crate) const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A piece of a college tree emitted by [`Client::stream_tree`].
+#[derive(Debug)]
+pub enum TreeEvent {
+    /// The college itself was loaded
+    CollegeLoaded(College),
+    /// A campus of the college was loaded
+    CampusLoaded(Campus),
+    /// The groups of a campus were loaded
+    GroupsLoaded { campus_id: u32, groups: Vec<Group> },
+    /// A request failed; no further events follow
+    Failed(Error),
+}
+
+/// A mismatch between embedded college data and the dedicated endpoints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// An embedded campus is not returned by the campuses endpoint
+    CampusNotListed { campus_id: u32 },
+    /// A listed campus is missing from the embedded data
+    CampusNotEmbedded { campus_id: u32 },
+    /// The embedded and listed campus names differ
+    CampusNameMismatch {
+        campus_id: u32,
+        embedded: String,
+        listed: String,
+    },
+    /// An embedded group is not returned by the groups endpoint
+    GroupNotListed { campus_id: u32, group_id: u32 },
+    /// A listed group is missing from the embedded data
+    GroupNotEmbedded { campus_id: u32, group_id: u32 },
+}
+
+/// Lesson counts of two groups over the same week, see [`Client::compare_load`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadComparison {
+    /// Per-day counts, sorted by date
+    pub days: Vec<DayLoad>,
+    /// Total lessons of the first group
+    pub total_a: usize,
+    /// Total lessons of the second group
+    pub total_b: usize,
+}
+
+/// Lesson counts of two groups on a single date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayLoad {
+    /// The date both counts refer to
+    pub date: NaiveDate,
+    /// Lessons of the first group on that date
+    pub a: usize,
+    /// Lessons of the second group on that date
+    pub b: usize,
+}
+
+/// How many calendar days `next_school_day` scans before giving up.
+const SCHOOL_DAY_HORIZON: u64 = 14;
+
+impl Client {
+    /// Finds the first school day on or after `from` that has at least one lesson.
+    ///
+    /// Every calendar day is checked, so Saturday classes are found; days off
+    /// and holidays show up as empty days and are skipped. Days are fetched
+    /// concurrently, but results are consumed in date order, so the earliest
+    /// matching day wins.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the student group
+    /// * `from` - The first date to consider
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if no lessons are found within the scan horizon.
+    pub async fn next_school_day(
+        &self,
+        group_id: u32,
+        from: NaiveDate,
+    ) -> Result<Option<Schedule>> {
+        let days =
+            (0..SCHOOL_DAY_HORIZON).filter_map(|offset| from.checked_add_days(Days::new(offset)));
+
+        let mut fetches = stream::iter(days)
+            .map(|date| async move {
+                let date = date.format("%Y-%m-%d").to_string();
+                self.limited(self.schedule(group_id).date(&date).send())
+                    .await
+            })
+            .buffered(self.fan_out_width());
+
+        while let Some(schedules) = fetches.next().await {
+            if let Some(schedule) = schedules?.into_iter().find(|s| !s.lessons.is_empty()) {
+                return Ok(Some(schedule));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Fetches a group's lessons for every day of a calendar month.
+    ///
+    /// Days are requested concurrently and collected into a map keyed by date.
+    /// Days the API returns no schedule for are absent from the map.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the student group
+    /// * `year` - Calendar year
+    /// * `month` - Calendar month, from 1 to 12
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if `year`/`month` do not form a valid month.
+    pub async fn month_schedule(
+        &self,
+        group_id: u32,
+        year: i32,
+        month: u32,
+    ) -> Result<BTreeMap<NaiveDate, Vec<Lesson>>> {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)
+            .ok_or_else(|| Error::Validation(format!("invalid month {}-{}", year, month)))?;
+        let days = first.iter_days().take_while(|date| date.month() == month);
+
+        stream::iter(days)
+            .map(|date| async move {
+                let date = date.format("%Y-%m-%d").to_string();
+                self.limited(self.schedule(group_id).date(&date).send())
+                    .await
+            })
+            .buffer_unordered(self.fan_out_width())
+            .try_fold(BTreeMap::new(), |mut month, schedules| async move {
+                for schedule in schedules {
+                    month
+                        .entry(schedule.date)
+                        .or_insert_with(Vec::new)
+                        .extend(schedule.lessons);
+                }
+                Ok(month)
+            })
+            .await
+    }
+
+    /// Lists the groups of every campus of a college.
+    ///
+    /// Campuses are listed first, then their groups are fetched with at most
+    /// `concurrency` requests in flight.
+    ///
+    /// # Arguments
+    ///
+    /// * `college_id` - The ID of the college
+    /// * `concurrency` - Maximum number of concurrent group requests (at least 1)
+    /// * `cancel` - Token that aborts the remaining requests when cancelled
+    ///
+    /// # Returns
+    ///
+    /// Groups sorted by ID, without duplicates.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Cancelled` if `cancel` fires before all groups arrive.
+    pub async fn all_groups(
+        &self,
+        college_id: u32,
+        concurrency: usize,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<Vec<Group>> {
+        let campuses = until_cancelled(cancel, CampusesQuery::new(self, college_id).send()).await?;
+
+        let groups = stream::iter(campuses)
+            .map(|campus| until_cancelled(cancel, self.limited(self.groups(campus.id).send())))
+            .buffer_unordered(concurrency.max(1))
+            .try_fold(BTreeMap::new(), |mut groups, batch| async move {
+                groups.extend(batch.into_iter().map(|group| (group.id, group)));
+                Ok(groups)
+            })
+            .await?;
+
+        Ok(groups.into_values().collect())
+    }
+
+    /// Collects the distinct teacher names across every group of a college.
+    ///
+    /// Walks campuses, then their groups, then each group's schedule for `week`.
+    /// Requests at each level are limited to the client's fan-out width.
+    ///
+    /// # Arguments
+    ///
+    /// * `college_id` - The ID of the college
+    /// * `week` - Which week's schedules to inspect
+    ///
+    /// # Returns
+    ///
+    /// Teacher names sorted alphabetically, without duplicates or blanks.
+    /// Co-taught lessons contribute each of their teachers separately.
+    pub async fn college_teachers(&self, college_id: u32, week: Week) -> Result<Vec<String>> {
+        let groups = self
+            .all_groups(college_id, self.fan_out_width(), None)
+            .await?;
+
+        let teachers = stream::iter(groups)
+            .map(|group| self.limited(self.schedule(group.id).week(week).send()))
+            .buffer_unordered(self.fan_out_width())
+            .try_fold(BTreeSet::new(), |mut teachers, schedules| async move {
+                let names = schedules.into_iter().flat_map(|s| s.lessons).flat_map(|l| {
+                    l.teachers()
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                });
+                teachers.extend(names);
+                Ok(teachers)
+            })
+            .await?;
+
+        Ok(teachers.into_iter().collect())
+    }
+
+    /// Streams a college tree as its pieces arrive.
+    ///
+    /// Emits `CollegeLoaded` first, then `CampusLoaded` for every campus, then
+    /// `GroupsLoaded` for each campus in completion order. Group rosters are
+    /// fetched concurrently. The stream ends after the first `Failed` event.
+    ///
+    /// # Arguments
+    ///
+    /// * `college_id` - The ID of the college
+    pub fn stream_tree(&self, college_id: u32) -> impl Stream<Item = TreeEvent> + '_ {
+        let college = stream::once(async move {
+            CollegeQuery::new(self, college_id)
+                .get()
+                .await
+                .map(TreeEvent::CollegeLoaded)
+        });
+
+        let campuses =
+            stream::once(async move { CampusesQuery::new(self, college_id).send().await })
+                .flat_map(move |result| match result {
+                    Ok(campuses) => {
+                        let loaded = stream::iter(campuses.clone())
+                            .map(|campus| Ok(TreeEvent::CampusLoaded(campus)));
+                        let groups = stream::iter(campuses)
+                            .map(move |campus| async move {
+                                let groups = self.limited(self.groups(campus.id).send()).await?;
+                                Ok(TreeEvent::GroupsLoaded {
+                                    campus_id: campus.id,
+                                    groups,
+                                })
+                            })
+                            .buffer_unordered(self.fan_out_width());
+                        loaded.chain(groups).left_stream()
+                    }
+                    Err(e) => stream::once(future::ready(Err(e))).right_stream(),
+                });
+
+        college.chain(campuses).scan(false, |failed, result| {
+            if *failed {
+                return future::ready(None);
+            }
+            future::ready(Some(result.unwrap_or_else(|e| {
+                *failed = true;
+                TreeEvent::Failed(e)
+            })))
+        })
+    }
+
+    /// Compares a college's embedded campuses and groups with the endpoints.
+    ///
+    /// Campuses are checked against the campuses endpoint. Groups are checked
+    /// only for embedded campuses that carry a non-empty group list, since the
+    /// API may omit them. Group rosters are fetched concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `college_id` - The ID of the college
+    ///
+    /// # Returns
+    ///
+    /// Every mismatch found; an empty list means the tree is consistent.
+    pub async fn verify_tree(&self, college_id: u32) -> Result<Vec<Inconsistency>> {
+        let college = CollegeQuery::new(self, college_id).get().await?;
+        let listed = CampusesQuery::new(self, college_id).send().await?;
+
+        let mut issues = Vec::new();
+        for campus in &college.campuses {
+            match listed.iter().find(|c| c.id == campus.id) {
+                None => issues.push(Inconsistency::CampusNotListed {
+                    campus_id: campus.id,
+                }),
+                Some(other) if other.name != campus.name => {
+                    issues.push(Inconsistency::CampusNameMismatch {
+                        campus_id: campus.id,
+                        embedded: campus.name.clone(),
+                        listed: other.name.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for campus in &listed {
+            if !college.campuses.iter().any(|c| c.id == campus.id) {
+                issues.push(Inconsistency::CampusNotEmbedded {
+                    campus_id: campus.id,
+                });
+            }
+        }
+
+        let embedded = college.campuses.iter().filter(|c| !c.groups.is_empty());
+        let group_issues: Vec<Inconsistency> = stream::iter(embedded)
+            .map(|campus| async move {
+                let fetched = self.limited(self.groups(campus.id).send()).await?;
+                let mut issues = Vec::new();
+                for group in &campus.groups {
+                    if !fetched.iter().any(|g| g.id == group.id) {
+                        issues.push(Inconsistency::GroupNotListed {
+                            campus_id: campus.id,
+                            group_id: group.id,
+                        });
+                    }
+                }
+                for group in &fetched {
+                    if !campus.groups.iter().any(|g| g.id == group.id) {
+                        issues.push(Inconsistency::GroupNotEmbedded {
+                            campus_id: campus.id,
+                            group_id: group.id,
+                        });
+                    }
+                }
+                Ok::<_, Error>(issues)
+            })
+            .buffered(self.fan_out_width())
+            .try_concat()
+            .await?;
+
+        issues.extend(group_issues);
+        Ok(issues)
+    }
+
+    /// Compares the weekly lesson load of two groups.
+    ///
+    /// Both weeks are fetched concurrently. The date axis is the union of the
+    /// dates returned for either group, so a day only one group studies on is
+    /// reported with a zero count for the other.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_a` - The ID of the first group
+    /// * `group_b` - The ID of the second group
+    /// * `week` - Which week to compare
+    pub async fn compare_load(
+        &self,
+        group_a: u32,
+        group_b: u32,
+        week: Week,
+    ) -> Result<LoadComparison> {
+        let (a, b) = future::try_join(
+            self.schedule(group_a).week(week).send(),
+            self.schedule(group_b).week(week).send(),
+        )
+        .await?;
+
+        let mut days: BTreeMap<NaiveDate, (usize, usize)> = BTreeMap::new();
+        for schedule in &a {
+            days.entry(schedule.date).or_default().0 += schedule.lessons.len();
+        }
+        for schedule in &b {
+            days.entry(schedule.date).or_default().1 += schedule.lessons.len();
+        }
+
+        let days: Vec<DayLoad> = days
+            .into_iter()
+            .map(|(date, (a, b))| DayLoad { date, a, b })
+            .collect();
+        Ok(LoadComparison {
+            total_a: days.iter().map(|d| d.a).sum(),
+            total_b: days.iter().map(|d| d.b).sum(),
+            days,
+        })
+    }
+
+    /// Finds the next lesson of each group on the day of `now`.
+    ///
+    /// The day is taken from `now` in its own timezone, and schedules are
+    /// fetched concurrently and bound to bell times in that timezone with
+    /// [`Schedule::timed_lessons`]. Groups with no lessons left are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_ids` - The IDs of the student groups
+    /// * `now` - The current instant in the college's timezone
+    /// * `calls` - The college's call schedule
+    ///
+    /// # Returns
+    ///
+    /// Pairs of group ID and its next lesson, sorted by start time.
+    pub async fn upcoming_across_groups(
+        &self,
+        group_ids: &[u32],
+        now: DateTime<Tz>,
+        calls: &[Call],
+    ) -> Result<Vec<(u32, TimedLesson)>> {
+        let date = now.date_naive().format("%Y-%m-%d").to_string();
+        let date = date.as_str();
+        let tz = now.timezone();
+        let now = now.with_timezone(&Utc);
+
+        let mut upcoming: Vec<(u32, TimedLesson)> = stream::iter(group_ids.iter().copied())
+            .map(|group_id| async move {
+                let schedules = self
+                    .limited(self.schedule(group_id).date(date).send())
+                    .await?;
+                let next = schedules
+                    .iter()
+                    .flat_map(|s| s.timed_lessons(calls, tz))
+                    .filter(|t| t.starts_at > now)
+                    .min_by_key(|t| t.starts_at);
+                Ok::<_, Error>(next.map(|t| (group_id, t)))
+            })
+            .buffer_unordered(self.fan_out_width())
+            .try_filter_map(|next| future::ready(Ok(next)))
+            .try_collect()
+            .await?;
+
+        upcoming.sort_by_key(|(_, t)| t.starts_at);
+        Ok(upcoming)
+    }
+
+    /// Creates a query fetching today's schedules of several groups concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_ids` - The IDs of the student groups
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use osars::{CancellationToken, Client};
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let cancel = CancellationToken::new();
+    /// let schedules = client
+    ///     .schedules_for(&[1, 2, 3])
+    ///     .concurrency(2)
+    ///     .cancel(&cancel)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn schedules_for<'a>(&'a self, group_ids: &'a [u32]) -> SchedulesForQuery<'a> {
+        SchedulesForQuery {
+            client: self,
+            group_ids,
+            concurrency: None,
+            cancel: None,
+        }
+    }
+
+    /// Like [`Client::schedules_for`], but keeps going after a failed group.
+    ///
+    /// Shorthand for `self.schedules_for(group_ids).send_each()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_ids` - The IDs of the student groups
+    pub async fn schedules_for_each(&self, group_ids: &[u32]) -> Vec<(u32, Result<Vec<Schedule>>)> {
+        self.schedules_for(group_ids).send_each().await
+    }
+}
+
+/// Today's schedules of several groups, created by [`Client::schedules_for`].
+///
+/// Requests run concurrently and any configured rate limit applies to each
+/// of them. Results keep the order of the group IDs.
+pub struct SchedulesForQuery<'a> {
+    client: &'a Client,
+    group_ids: &'a [u32],
+    concurrency: Option<usize>,
+    cancel: Option<&'a CancellationToken>,
+}
+
+impl<'a> SchedulesForQuery<'a> {
+    /// Caps the number of requests in flight at once.
+    ///
+    /// Defaults to the client's fan-out width. Values below 1 are raised to 1.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
+    /// Aborts the remaining requests when `cancel` fires.
+    pub fn cancel(mut self, cancel: &'a CancellationToken) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Sends the requests and returns every group's schedules.
+    ///
+    /// # Returns
+    ///
+    /// Pairs of group ID and its schedules, in the order of the group IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered; use [`send_each`](Self::send_each)
+    /// to keep per-group results instead. Returns `Error::Cancelled` if the
+    /// cancellation token fires first, dropping the requests still in flight.
+    pub async fn send(self) -> Result<Vec<(u32, Vec<Schedule>)>> {
+        let width = self.width();
+        self.fetches()
+            .map(
+                |(group_id, schedules)| async move { Ok::<_, Error>((group_id, schedules.await?)) },
+            )
+            .buffered(width)
+            .try_collect()
+            .await
+    }
+
+    /// Sends the requests and returns each group's result, keeping going
+    /// after a failed group.
+    ///
+    /// Groups still pending when the cancellation token fires yield
+    /// `Error::Cancelled`.
+    pub async fn send_each(self) -> Vec<(u32, Result<Vec<Schedule>>)> {
+        let width = self.width();
+        self.fetches()
+            .map(|(group_id, schedules)| async move { (group_id, schedules.await) })
+            .buffered(width)
+            .collect()
+            .await
+    }
+
+    fn width(&self) -> usize {
+        self.concurrency
+            .unwrap_or_else(|| self.client.fan_out_width())
+            .max(1)
+    }
+
+    /// Pairs each group ID with the lazy request for its schedules.
+    fn fetches(
+        &self,
+    ) -> impl Stream<Item = (u32, impl Future<Output = Result<Vec<Schedule>>> + 'a)> + 'a {
+        let client = self.client;
+        let cancel = self.cancel;
+        stream::iter(self.group_ids.iter().copied()).map(move |group_id| {
+            let schedules = until_cancelled(cancel, client.limited(client.today(group_id).send()));
+            (group_id, schedules)
+        })
+    }
+}
+
+/// Runs `request` unless `cancel` fires first.
+async fn until_cancelled<T>(
+    cancel: Option<&CancellationToken>,
+    request: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match cancel {
+        Some(cancel) => cancel
+            .run_until_cancelled(request)
+            .await
+            .unwrap_or(Err(Error::Cancelled)),
+        None => request.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_next_school_day_skips_empty_days() {
+        let mut server = Server::new_async().await;
+        let _empty = server
+            .mock("GET", Matcher::Regex(r"^/groups/7/schedules".to_string()))
+            .with_status(200)
+            .with_body("[]")
+            .expect_at_least(0)
+            .create_async()
+            .await;
+        let lessons = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-19".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-19", "lessons": [{
+                    "title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                    "startTime": "09:00:00", "endTime": "10:30:00"
+                }]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let monday = NaiveDate::from_ymd_opt(2025, 11, 17).unwrap();
+        let schedule = client.next_school_day(7, monday).await.unwrap().unwrap();
+
+        lessons.assert_async().await;
+        assert_eq!(
+            schedule.date,
+            NaiveDate::from_ymd_opt(2025, 11, 19).unwrap()
+        );
+        assert_eq!(schedule.lessons[0].title, "Math");
+    }
+
+    #[tokio::test]
+    async fn test_next_school_day_finds_saturday_lessons() {
+        let mut server = Server::new_async().await;
+        let _empty = server
+            .mock("GET", Matcher::Regex(r"^/groups/7/schedules".to_string()))
+            .with_status(200)
+            .with_body("[]")
+            .expect_at_least(0)
+            .create_async()
+            .await;
+        let saturday = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-22".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-22", "lessons": [{
+                    "title": "Lab", "cabinet": "201", "teacher": "Ann", "order": 1,
+                    "startTime": "09:00:00", "endTime": "10:30:00"
+                }]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let friday = NaiveDate::from_ymd_opt(2025, 11, 21).unwrap();
+        let schedule = client.next_school_day(7, friday).await.unwrap().unwrap();
+
+        saturday.assert_async().await;
+        assert_eq!(schedule.lessons[0].title, "Lab");
+    }
+
+    #[tokio::test]
+    async fn test_next_school_day_none_within_horizon() {
+        let mut server = Server::new_async().await;
+        let _empty = server
+            .mock("GET", Matcher::Regex(r"^/groups/7/schedules".to_string()))
+            .with_status(200)
+            .with_body("[]")
+            .expect_at_least(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let monday = NaiveDate::from_ymd_opt(2025, 11, 17).unwrap();
+        let schedule = client.next_school_day(7, monday).await.unwrap();
+
+        assert!(schedule.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_month_schedule_keys() {
+        let mut server = Server::new_async().await;
+        for date in ["2026-02-02", "2026-02-27"] {
+            server
+                .mock("GET", "/groups/7/schedules")
+                .match_query(Matcher::UrlEncoded("date".into(), date.into()))
+                .with_status(200)
+                .with_body(format!(
+                    r#"[{{"groupId": 7, "date": "{}", "lessons": [{{
+                        "title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                        "startTime": "09:00:00", "endTime": "10:30:00"
+                    }}]}}]"#,
+                    date
+                ))
+                .create_async()
+                .await;
+        }
+
+        let _empty = server
+            .mock("GET", Matcher::Regex(r"^/groups/7/schedules".to_string()))
+            .with_status(200)
+            .with_body("[]")
+            .expect(26)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let month = client.month_schedule(7, 2026, 2).await.unwrap();
+
+        let keys: Vec<String> = month.keys().map(|d| d.to_string()).collect();
+        assert_eq!(keys, ["2026-02-02", "2026-02-27"]);
+        assert_eq!(month.values().map(Vec::len).sum::<usize>(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_month_schedule_invalid_month() {
+        let client = Client::new("https://api.example.com");
+        let result = client.month_schedule(7, 2026, 13).await;
+
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_college_teachers_aggregates_distinct_names() {
+        let mut server = Server::new_async().await;
+        let campuses = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(r#"[{"campusId": 10, "name": "Main", "collegeId": 1}]"#)
+            .create_async()
+            .await;
+        let groups = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 1, "name": "A", "campusId": 10},
+                    {"studentGroupId": 2, "name": "B", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+        let mut schedules = Vec::new();
+        for (group_id, teachers) in [(1, ["Smith", "Brown"]), (2, ["Adams", "Brown, Clark"])] {
+            let lessons: Vec<String> = teachers
+                .iter()
+                .map(|t| {
+                    format!(
+                        r#"{{"title": "Math", "cabinet": "101", "teacher": "{}", "order": 1,
+                            "startTime": "09:00:00", "endTime": "10:30:00"}}"#,
+                        t
+                    )
+                })
+                .collect();
+            let mock = server
+                .mock("GET", format!("/groups/{}/schedules", group_id).as_str())
+                .match_query(Matcher::UrlEncoded("week".into(), "current".into()))
+                .with_status(200)
+                .with_body(format!(
+                    r#"[{{"groupId": {}, "date": "2025-11-17", "lessons": [{}]}}]"#,
+                    group_id,
+                    lessons.join(",")
+                ))
+                .create_async()
+                .await;
+            schedules.push(mock);
+        }
+
+        let client = Client::new(&server.url());
+        let teachers = client.college_teachers(1, Week::Current).await.unwrap();
+
+        campuses.assert_async().await;
+        groups.assert_async().await;
+        for mock in schedules {
+            mock.assert_async().await;
+        }
+        assert_eq!(teachers, ["Adams", "Brown", "Clark", "Smith"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_tree_event_order() {
+        let mut server = Server::new_async().await;
+        let _college = server
+            .mock("GET", "/colleges/1")
+            .with_status(200)
+            .with_body(r#"{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}"#)
+            .create_async()
+            .await;
+        let _campuses = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(
+                r#"[{"campusId": 10, "name": "Main", "collegeId": 1},
+                    {"campusId": 11, "name": "North", "collegeId": 1}]"#,
+            )
+            .create_async()
+            .await;
+        let _groups = server
+            .mock(
+                "GET",
+                Matcher::Regex(r"^/campuses/1[01]/groups$".to_string()),
+            )
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 1, "name": "A", "campusId": 10}]"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let events: Vec<TreeEvent> = client.stream_tree(1).collect().await;
+
+        let kinds: Vec<&str> = events
+            .iter()
+            .map(|event| match event {
+                TreeEvent::CollegeLoaded(_) => "college",
+                TreeEvent::CampusLoaded(_) => "campus",
+                TreeEvent::GroupsLoaded { .. } => "groups",
+                TreeEvent::Failed(_) => "failed",
+            })
+            .collect();
+        assert_eq!(kinds, ["college", "campus", "campus", "groups", "groups"]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_tree_stops_after_failure() {
+        let mut server = Server::new_async().await;
+        let _college = server
+            .mock("GET", "/colleges/1")
+            .with_status(404)
+            .with_body(r#"{"error": "Not found"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let events: Vec<TreeEvent> = client.stream_tree(1).collect().await;
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], TreeEvent::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn test_verify_tree_reports_mismatches() {
+        let mut server = Server::new_async().await;
+        let _college = server
+            .mock("GET", "/colleges/1")
+            .with_status(200)
+            .with_body(
+                r#"{"collegeId": 1, "name": "Test College", "calls": [], "campuses": [
+                    {"campusId": 10, "name": "Main", "collegeId": 1, "groups": [
+                        {"studentGroupId": 1, "name": "A", "campusId": 10},
+                        {"studentGroupId": 2, "name": "B", "campusId": 10}
+                    ]},
+                    {"campusId": 12, "name": "Closed", "collegeId": 1}
+                ]}"#,
+            )
+            .create_async()
+            .await;
+        let _campuses = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(r#"[{"campusId": 10, "name": "Main", "collegeId": 1}]"#)
+            .create_async()
+            .await;
+        let groups = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 1, "name": "A", "campusId": 10}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let issues = client.verify_tree(1).await.unwrap();
+
+        groups.assert_async().await;
+        assert_eq!(
+            issues,
+            [
+                Inconsistency::CampusNotListed { campus_id: 12 },
+                Inconsistency::GroupNotListed {
+                    campus_id: 10,
+                    group_id: 2
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compare_load_aligns_dates() {
+        let lesson = r#"{"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+            "startTime": "09:00:00", "endTime": "10:30:00"}"#;
+        let mut server = Server::new_async().await;
+        let _a = server
+            .mock("GET", "/groups/1/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "next".into()))
+            .with_status(200)
+            .with_body(format!(
+                r#"[{{"groupId": 1, "date": "2025-11-17", "lessons": [{0}, {0}]}},
+                    {{"groupId": 1, "date": "2025-11-18", "lessons": [{0}]}}]"#,
+                lesson
+            ))
+            .create_async()
+            .await;
+        let _b = server
+            .mock("GET", "/groups/2/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "next".into()))
+            .with_status(200)
+            .with_body(format!(
+                r#"[{{"groupId": 2, "date": "2025-11-18", "lessons": [{0}, {0}, {0}]}},
+                    {{"groupId": 2, "date": "2025-11-19", "lessons": [{0}]}}]"#,
+                lesson
+            ))
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let load = client.compare_load(1, 2, Week::Next).await.unwrap();
+
+        let days: Vec<(String, usize, usize)> = load
+            .days
+            .iter()
+            .map(|d| (d.date.to_string(), d.a, d.b))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                ("2025-11-17".to_string(), 2, 0),
+                ("2025-11-18".to_string(), 1, 3),
+                ("2025-11-19".to_string(), 0, 1),
+            ]
+        );
+        assert_eq!((load.total_a, load.total_b), (3, 4));
+    }
+
+    #[tokio::test]
+    async fn test_upcoming_across_groups_chronological() {
+        let mut server = Server::new_async().await;
+        let _a = server
+            .mock("GET", "/groups/1/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-17".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 1, "date": "2025-11-17", "lessons": [
+                    {"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"},
+                    {"title": "History", "cabinet": "103", "teacher": "Kate", "order": 3,
+                     "startTime": "12:30:00", "endTime": "14:00:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await;
+        let _b = server
+            .mock("GET", "/groups/2/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-17".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 2, "date": "2025-11-17", "lessons": [
+                    {"title": "Physics", "cabinet": "102", "teacher": "Ann", "order": 2,
+                     "startTime": "10:40:00", "endTime": "12:10:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let now = chrono_tz::Asia::Yekaterinburg
+            .with_ymd_and_hms(2025, 11, 17, 10, 0, 0)
+            .unwrap();
+        let upcoming = client
+            .upcoming_across_groups(&[1, 2], now, &[])
+            .await
+            .unwrap();
+
+        let order: Vec<(u32, &str)> = upcoming
+            .iter()
+            .map(|(group_id, t)| (*group_id, t.lesson.title.as_str()))
+            .collect();
+        assert_eq!(order, [(2, "Physics"), (1, "History")]);
+        assert_eq!(
+            upcoming[0].1.starts_at.to_rfc3339(),
+            "2025-11-17T05:40:00+00:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_upcoming_across_groups_uses_local_date() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/1/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-18".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 1, "date": "2025-11-18", "lessons": [
+                    {"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await;
+
+        // Still the 17th in UTC, but already the 18th at the college.
+        let client = Client::new(&server.url());
+        let now = chrono_tz::Asia::Yekaterinburg
+            .with_ymd_and_hms(2025, 11, 18, 0, 30, 0)
+            .unwrap();
+        let upcoming = client.upcoming_across_groups(&[1], now, &[]).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(
+            upcoming[0].1.starts_at.to_rfc3339(),
+            "2025-11-18T04:00:00+00:00"
+        );
+    }
+
+    async fn today_mock(server: &mut Server, group_id: u32, status: usize) -> mockito::Mock {
+        server
+            .mock("GET", format!("/groups/{}/schedules", group_id).as_str())
+            .match_query(Matcher::UrlEncoded("day".into(), "today".into()))
+            .with_status(status)
+            .with_body(format!(
+                r#"[{{"groupId": {}, "date": "2025-11-17", "lessons": []}}]"#,
+                group_id
+            ))
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_schedules_for_returns_every_group() {
+        let mut server = Server::new_async().await;
+        let mut mocks = Vec::new();
+        for group_id in [1, 2, 3] {
+            mocks.push(today_mock(&mut server, group_id, 200).await);
+        }
+
+        let client = Client::new(&server.url());
+        let schedules = client.schedules_for(&[3, 1, 2]).send().await.unwrap();
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+        let ids: Vec<u32> = schedules.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [3, 1, 2]);
+        assert!(schedules.iter().all(|(id, s)| s[0].group_id == *id));
+    }
+
+    #[tokio::test]
+    async fn test_schedules_for_each_keeps_failures() {
+        let mut server = Server::new_async().await;
+        let _ok = today_mock(&mut server, 1, 200).await;
+        let _missing = today_mock(&mut server, 2, 404).await;
+
+        let client = Client::new(&server.url());
+        let results = client.schedules_for_each(&[1, 2]).await;
+
+        assert!(results[0].1.is_ok());
+        assert!(matches!(results[1].1, Err(Error::NotFound { .. })));
+        assert!(client.schedules_for(&[1, 2]).send().await.is_err());
+    }
+
+    #[derive(Default)]
+    struct Slow {
+        in_flight: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    impl crate::HttpBackend for Slow {
+        fn execute(
+            &self,
+            _request: crate::HttpRequest,
+        ) -> futures::future::BoxFuture<'_, Result<crate::HttpResponse>> {
+            use std::sync::atomic::Ordering::SeqCst;
+            Box::pin(async move {
+                let now = self.in_flight.fetch_add(1, SeqCst) + 1;
+                self.peak.fetch_max(now, SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                self.in_flight.fetch_sub(1, SeqCst);
+                Ok(crate::HttpResponse {
+                    status: 200,
+                    body: "[]".to_string(),
+                    ..crate::HttpResponse::default()
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schedules_for_concurrency_cap() {
+        let backend = std::sync::Arc::new(Slow::default());
+        let client = Client::new("https://api.example.com").with_backend(backend.clone());
+        let group_ids: Vec<u32> = (1..=6).collect();
+
+        let results = client
+            .schedules_for(&group_ids)
+            .concurrency(2)
+            .send_each()
+            .await;
+
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+        assert_eq!(backend.peak.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_all_groups_dedups_across_campuses() {
+        let mut server = Server::new_async().await;
+        let _campuses = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(
+                r#"[{"campusId": 10, "name": "Main", "collegeId": 1},
+                    {"campusId": 11, "name": "North", "collegeId": 1}]"#,
+            )
+            .create_async()
+            .await;
+        let _main = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 2, "name": "B", "campusId": 10},
+                    {"studentGroupId": 1, "name": "A", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+        let _north = server
+            .mock("GET", "/campuses/11/groups")
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 3, "name": "C", "campusId": 11},
+                    {"studentGroupId": 2, "name": "B", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let groups = client.all_groups(1, 2, None).await.unwrap();
+
+        let ids: Vec<u32> = groups.iter().map(|g| g.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_schedules_for_cancelled_mid_flight() {
+        let completed = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = completed.clone();
+        let mut server = Server::new_async().await;
+        let _slow = server
+            .mock("GET", Matcher::Regex(r"^/groups/\d+/schedules$".into()))
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body_from_request(move |_| {
+                std::thread::sleep(std::time::Duration::from_millis(300));
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                b"[]".to_vec()
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            trigger.cancel();
+        });
+
+        let group_ids: Vec<u32> = (1..=8).collect();
+        let result = client
+            .schedules_for(&group_ids)
+            .cancel(&cancel)
+            .send()
+            .await;
+
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert!(completed.load(std::sync::atomic::Ordering::SeqCst) < group_ids.len());
+    }
+
+    #[tokio::test]
+    async fn test_all_groups_cancelled_before_start() {
+        let client = Client::new("http://127.0.0.1:9");
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        let result = client.all_groups(1, 2, Some(&cancel)).await;
+
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::api::groups::{GroupQuery, GroupsQuery};
+use crate::{Campus, Client, error::Result};
+use urlencoding::encode;
+
+pub struct CampusesQuery<'a> {
+    client: &'a Client,
+    college_id: u32,
+    name: Option<String>,
+}
+
+impl<'a> CampusesQuery<'a> {
+    pub fn new(client: &'a Client, college_id: u32) -> Self {
+        Self {
+            client,
+            college_id,
+            name: None,
+        }
+    }
+
+    /// Filters campuses by name, sent URL-encoded as the `name` parameter.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub async fn send(self) -> Result<Vec<Campus>> {
+        self.client.get_json(&self.path()).await
+    }
+
+    /// Returns the full request URL, including query parameters, without sending.
+    pub fn url(&self) -> String {
+        self.client.build_url(&self.path())
+    }
+
+    fn path(&self) -> String {
+        let mut path = format!("/colleges/{}/campuses", self.college_id);
+
+        if let Some(name) = &self.name {
+            path = format!("{}?name={}", path, encode(name));
+        }
+        path
+    }
+
+    pub fn campus(self, campus_id: u32) -> CampusQuery<'a> {
+        CampusQuery::new(self.client, campus_id)
+    }
+}
+
+pub struct CampusQuery<'a> {
+    client: &'a Client,
+    campus_id: u32,
+}
+
+impl<'a> CampusQuery<'a> {
+    pub fn new(client: &'a Client, campus_id: u32) -> Self {
+        Self { client, campus_id }
+    }
+
+    pub async fn get(self) -> Result<Campus> {
+        self.client
+            .get_json(&format!("/campuses/{}", self.campus_id))
+            .await
+    }
+
+    pub fn groups(self) -> GroupsQuery<'a> {
+        GroupsQuery::new(self.client, self.campus_id)
+    }
+    pub fn group(self, group_id: u32) -> GroupQuery<'a> {
+        GroupQuery::new(self.client, group_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_campuses_name_filter_from_both_entry_points() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges/1/campuses")
+            .match_query(Matcher::UrlEncoded("name".into(), "Main".into()))
+            .with_status(200)
+            .with_body(r#"[{"campusId": 10, "name": "Main", "collegeId": 1}]"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_college(1);
+        let from_client: CampusesQuery<'_> = client.campuses().unwrap();
+        let from_college: CampusesQuery<'_> = client.colleges().college(1).campuses();
+
+        let first = from_client.name("Main").send().await.unwrap();
+        let second = from_college.name("Main").send().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(first[0].id, 10);
+        assert_eq!(second[0].id, 10);
+    }
+
+    #[tokio::test]
+    async fn test_campuses_name_is_url_encoded() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges/1/campuses")
+            .match_query(Matcher::Exact("name=North%20Wing%20%26%20Annex".into()))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let campuses = CampusesQuery::new(&client, 1)
+            .name("North Wing & Annex")
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert!(campuses.is_empty());
+    }
+}
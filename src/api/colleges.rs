@@ -1,28 +1,146 @@
-use crate::{Campus, Client, College, api::groups::GroupsQuery, error::Result};
+pub use super::campuses::{CampusQuery, CampusesQuery};
+use super::paging::{DEFAULT_LIMIT, collect_pages};
+use crate::utils::Envelope;
+use crate::{Client, College, ResponseMeta, error::Result};
 use urlencoding::encode;
 
 pub struct CollegesQuery<'a> {
     client: &'a Client,
     name: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
 }
 
 impl<'a> CollegesQuery<'a> {
     pub fn new(client: &'a Client) -> Self {
-        Self { client, name: None }
+        Self {
+            client,
+            name: None,
+            limit: None,
+            offset: None,
+        }
     }
 
+    /// Filters colleges by name, sent URL-encoded as the `name` parameter.
     pub fn name(mut self, name: &str) -> Self {
         self.name = Some(name.to_string());
         self
     }
 
+    /// Limits the number of colleges returned.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the given number of colleges.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
     pub async fn send(self) -> Result<Vec<College>> {
-        let path = if let Some(name) = self.name {
-            format!("/colleges?name={}", name)
-        } else {
+        self.send_with_meta().await.map(|(colleges, _)| colleges)
+    }
+
+    /// Sends the query and returns the colleges together with response metadata.
+    ///
+    /// The metadata carries the HTTP status, the elapsed time and whether the
+    /// result was served from cache.
+    pub async fn send_with_meta(self) -> Result<(Vec<College>, ResponseMeta)> {
+        self.client.get_json_with_meta(&self.path()).await
+    }
+
+    /// Sends the query, accepting a response wrapped as `{ "data": [...], "meta": {...} }`.
+    ///
+    /// Bare array responses are accepted as well and yield no metadata.
+    pub async fn send_enveloped(self) -> Result<Envelope<College>> {
+        self.client.get_json(&self.path()).await
+    }
+
+    /// Fetches the colleges and keeps those whose name contains `query`.
+    ///
+    /// Matching is a case-insensitive substring search done client-side, for
+    /// servers that only match names exactly. The whole listing is downloaded
+    /// on every call, so prefer [`name`](Self::name) when an exact name is known.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use osars::Client;
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let colleges = client.colleges().name_contains("polytech").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn name_contains(self, query: &str) -> Result<Vec<College>> {
+        let query = query.to_lowercase();
+        self.matching(|college| college.name.to_lowercase().contains(&query))
+            .await
+    }
+
+    /// Fetches the colleges and keeps those accepted by `predicate`.
+    ///
+    /// Like [`name_contains`](Self::name_contains), this downloads the whole
+    /// listing and filters it client-side.
+    pub async fn matching<F>(self, predicate: F) -> Result<Vec<College>>
+    where
+        F: Fn(&College) -> bool,
+    {
+        let mut colleges = self.send().await?;
+        colleges.retain(|college| predicate(college));
+        Ok(colleges)
+    }
+
+    /// Fetches every college by following `limit`/`offset` pages.
+    ///
+    /// Starts at the configured offset (or zero) and pages by the configured
+    /// limit (or 100) until a page comes back short. If the server ignores
+    /// paging and returns its whole listing, that single response is used.
+    pub async fn send_all(self) -> Result<Vec<College>> {
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT);
+        collect_pages(
+            self.offset.unwrap_or(0),
+            limit,
+            |c: &College| c.college_id,
+            |offset| {
+                let page = CollegesQuery {
+                    client: self.client,
+                    name: self.name.clone(),
+                    limit: Some(limit),
+                    offset: Some(offset),
+                };
+                async move { page.send().await }
+            },
+        )
+        .await
+    }
+
+    /// Returns the full request URL, including query parameters, without sending.
+    pub fn url(&self) -> String {
+        self.client.build_url(&self.path())
+    }
+
+    fn path(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(name) = &self.name {
+            params.push(format!("name={}", encode(name)));
+        }
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+
+        if params.is_empty() {
             "/colleges".to_string()
-        };
-        self.client.get_json(&path).await
+        } else {
+            format!("/colleges?{}", params.join("&"))
+        }
     }
 
     pub fn college(self, college_id: u32) -> CollegeQuery<'a> {
@@ -54,60 +172,196 @@ impl<'a> CollegeQuery<'a> {
     }
 }
 
-pub struct CampusesQuery<'a> {
-    client: &'a Client,
-    college_id: u32,
-    name: Option<String>,
+impl Client {
+    /// Maps campus names of a college to their IDs.
+    ///
+    /// The campus list is fetched once and names are compared
+    /// case-insensitively, ignoring surrounding whitespace.
+    ///
+    /// # Arguments
+    ///
+    /// * `college_id` - The ID of the college
+    /// * `names` - Campus names to resolve
+    ///
+    /// # Returns
+    ///
+    /// One entry per requested name, in input order, with `None` for names
+    /// that matched no campus.
+    pub async fn resolve_campus_ids(
+        &self,
+        college_id: u32,
+        names: &[&str],
+    ) -> Result<Vec<(String, Option<u32>)>> {
+        let campuses = CampusesQuery::new(self, college_id).send().await?;
+
+        Ok(names
+            .iter()
+            .map(|name| {
+                let wanted = name.trim().to_lowercase();
+                let id = campuses
+                    .iter()
+                    .find(|c| c.name.trim().to_lowercase() == wanted)
+                    .map(|c| c.id);
+                (name.to_string(), id)
+            })
+            .collect())
+    }
 }
 
-impl<'a> CampusesQuery<'a> {
-    pub fn new(client: &'a Client, college_id: u32) -> Self {
-        Self {
-            client,
-            college_id,
-            name: None,
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_colleges_send_with_meta() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body(r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let (colleges, meta) = client.colleges().send_with_meta().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(colleges.len(), 1);
+        assert_eq!(colleges[0].name, "Test College");
+        assert_eq!(meta.status, 200);
+        assert!(!meta.from_cache);
+        assert!(meta.elapsed > std::time::Duration::ZERO);
     }
 
-    pub fn name(mut self, name: &str) -> Self {
-        self.name = Some(name.to_string());
-        self
+    #[tokio::test]
+    async fn test_colleges_send_enveloped() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body(
+                r#"{"data": [{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}],
+                    "meta": {"total": 1}}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let envelope = client.colleges().send_enveloped().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(envelope.data[0].name, "Test College");
+        assert_eq!(envelope.meta.unwrap()["total"], 1);
     }
 
-    pub async fn send(self) -> Result<Vec<Campus>> {
-        let mut url = format!("/colleges/{}/campuses", self.college_id);
+    #[tokio::test]
+    async fn test_colleges_send_all_stops_on_short_page() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges?limit=2&offset=0")
+            .with_status(200)
+            .with_body(r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#)
+            .expect(1)
+            .create_async()
+            .await;
 
-        if let Some(name) = self.name {
-            url = format!("{}?name={}", url, encode(&name));
-        }
-        self.client.get_json(&url).await
+        let client = Client::new(&server.url());
+        let colleges = client.colleges().limit(2).send_all().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(colleges.len(), 1);
     }
 
-    pub fn campus(self, campus_id: u32) -> CampusQuery<'a> {
-        CampusQuery::new(self.client, campus_id)
+    async fn three_colleges(server: &mut Server) -> mockito::Mock {
+        server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body(
+                r#"[{"collegeId": 1, "name": "City Polytechnic", "calls": [], "campuses": []},
+                    {"collegeId": 2, "name": "Art College", "calls": [], "campuses": []},
+                    {"collegeId": 3, "name": "polytech of the North", "calls": [], "campuses": []}]"#,
+            )
+            .create_async()
+            .await
     }
-}
 
-pub struct CampusQuery<'a> {
-    client: &'a Client,
-    campus_id: u32,
-}
+    #[tokio::test]
+    async fn test_colleges_name_contains() {
+        let mut server = Server::new_async().await;
+        let mock = three_colleges(&mut server).await;
 
-impl<'a> CampusQuery<'a> {
-    pub fn new(client: &'a Client, campus_id: u32) -> Self {
-        Self { client, campus_id }
+        let client = Client::new(&server.url());
+        let colleges = client.colleges().name_contains("POLYTECH").await.unwrap();
+
+        mock.assert_async().await;
+        let ids: Vec<u32> = colleges.iter().map(|c| c.college_id).collect();
+        assert_eq!(ids, [1, 3]);
     }
 
-    pub async fn get(self) -> Result<Campus> {
-        self.client
-            .get_json(&format!("/campuses/{}", self.campus_id))
+    #[tokio::test]
+    async fn test_colleges_matching() {
+        let mut server = Server::new_async().await;
+        let _mock = three_colleges(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let colleges = client
+            .colleges()
+            .matching(|college| college.name.starts_with("Art"))
             .await
+            .unwrap();
+
+        assert_eq!(colleges.len(), 1);
+        assert_eq!(colleges[0].college_id, 2);
     }
 
-    pub fn groups(self) -> GroupsQuery<'a> {
-        GroupsQuery::new(self.client, self.campus_id)
+    #[test]
+    fn test_colleges_url() {
+        let client = Client::new("https://api.example.com/");
+        let query = client.colleges().name("Tech");
+
+        assert_eq!(query.url(), "https://api.example.com/colleges?name=Tech");
+    }
+
+    #[test]
+    fn test_colleges_name_is_url_encoded() {
+        let client = Client::new("https://api.example.com");
+        let query = client.colleges().name("Arts & Crafts").limit(5);
+
+        assert_eq!(
+            query.url(),
+            "https://api.example.com/colleges?name=Arts%20%26%20Crafts&limit=5"
+        );
     }
-    pub fn group(self, group_id: u32) -> crate::api::groups::GroupQuery<'a> {
-        crate::api::groups::GroupQuery::new(self.client, group_id)
+
+    #[tokio::test]
+    async fn test_resolve_campus_ids() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(
+                r#"[{"campusId": 10, "name": "Main", "collegeId": 1},
+                    {"campusId": 11, "name": "North Wing", "collegeId": 1}]"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let resolved = client
+            .resolve_campus_ids(1, &["north wing", "MAIN", "South"])
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            resolved,
+            [
+                ("north wing".to_string(), Some(11)),
+                ("MAIN".to_string(), Some(10)),
+                ("South".to_string(), None),
+            ]
+        );
     }
 }
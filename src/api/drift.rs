@@ -0,0 +1,131 @@
+use crate::{Client, error::Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Differences between a raw API response and a typed model.
+///
+/// Keys are dotted paths such as `campuses[].groups[].name`, where `[]`
+/// stands for any array element.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Keys present in the response but not in the typed model
+    pub extra_keys: Vec<String>,
+    /// Keys present in the typed model but not in the response
+    pub missing_keys: Vec<String>,
+}
+
+impl DriftReport {
+    /// Returns `true` if the response and the model have the same keys.
+    pub fn is_clean(&self) -> bool {
+        self.extra_keys.is_empty() && self.missing_keys.is_empty()
+    }
+}
+
+impl Client {
+    /// Fetches `path` and compares its JSON keys with the model `T`.
+    ///
+    /// The response is parsed into `T` and serialized back; keys that do not
+    /// survive the round trip were dropped by the model, and keys that only
+    /// appear afterwards were filled in by it. This helps catch upstream
+    /// schema changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Serialization` if the response cannot be parsed into `T`.
+    pub async fn detect_drift<T>(&self, path: &str) -> Result<DriftReport>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let raw: Value = self.get_json(path).await?;
+        let typed: T = serde_json::from_value(raw.clone())?;
+        let typed = serde_json::to_value(typed)?;
+
+        let mut raw_keys = BTreeSet::new();
+        collect_keys(&raw, "", &mut raw_keys);
+        let mut typed_keys = BTreeSet::new();
+        collect_keys(&typed, "", &mut typed_keys);
+
+        Ok(DriftReport {
+            extra_keys: raw_keys.difference(&typed_keys).cloned().collect(),
+            missing_keys: typed_keys.difference(&raw_keys).cloned().collect(),
+        })
+    }
+}
+
+fn collect_keys(value: &Value, prefix: &str, keys: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                collect_keys(value, &path, keys);
+                keys.insert(path);
+            }
+        }
+        Value::Array(items) => {
+            let path = format!("{}[]", prefix);
+            for item in items {
+                collect_keys(item, &path, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Group, Schedule};
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_detect_drift_reports_extra_key() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 1, "name": "A", "campusId": 10, "course": 2}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let report = client
+            .detect_drift::<Vec<Group>>("/campuses/10/groups")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(report.extra_keys, ["[].course"]);
+        assert!(report.missing_keys.is_empty());
+        assert!(!report.is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_detect_drift_on_schedules() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-17", "lessons": [
+                    {"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let report = client
+            .detect_drift::<Vec<Schedule>>("/groups/7/schedules")
+            .await
+            .unwrap();
+
+        assert!(report.is_clean(), "{:?}", report);
+    }
+}
@@ -1,11 +1,29 @@
 use super::ScheduleQuery;
+use super::paging::{DEFAULT_LIMIT, collect_pages};
 use crate::models::Week;
-use crate::{Client, Group, error::Result};
+use crate::{Client, Group, error::Error, error::Result};
+use futures::stream::{self, Stream, TryStreamExt};
+use std::collections::BTreeMap;
+use urlencoding::encode;
 
+/// Page size used by `GroupsQuery::stream` when none is set.
+const DEFAULT_PER_PAGE: u32 = 100;
+
+/// A query listing the groups of a campus.
+///
+/// Deployments differ in which paging scheme they honour: some page by
+/// `page`/`per_page`, others by `limit`/`offset`, and some ignore both and
+/// return the whole listing. Use [`stream`](Self::stream) with the former and
+/// [`send_all`](Self::send_all) with the latter; both fall back to the single
+/// response when paging is ignored. The two schemes cannot be combined.
 pub struct GroupsQuery<'a> {
     client: &'a Client,
     campus_id: u32,
     name: Option<String>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+    limit: Option<u32>,
+    offset: Option<u32>,
 }
 
 impl<'a> GroupsQuery<'a> {
@@ -14,21 +32,198 @@ impl<'a> GroupsQuery<'a> {
             client,
             campus_id,
             name: None,
+            page: None,
+            per_page: None,
+            limit: None,
+            offset: None,
         }
     }
 
+    /// Filters groups by name, sent URL-encoded as the `name` parameter.
     pub fn name(mut self, name: &str) -> Self {
         self.name = Some(name.to_string());
         self
     }
 
+    /// Requests a single page of groups, starting from 1.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Sets the number of groups per page.
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    /// Limits the number of groups returned, for servers paging by offset.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the given number of groups, for servers paging by offset.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sends the query and returns one response's worth of groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if `page`/`per_page` is combined with
+    /// `limit`/`offset`.
     pub async fn send(self) -> Result<Vec<Group>> {
-        let path = if let Some(name) = self.name {
-            format!("/campuses/{}/groups?name={}", self.campus_id, name)
+        self.validate(true, true)?;
+        self.client.get_json(&self.path()).await
+    }
+
+    /// Rejects paging parameters of a scheme the caller does not follow.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - Whether `page`/`per_page` may be set
+    /// * `offsets` - Whether `limit`/`offset` may be set
+    fn validate(&self, pages: bool, offsets: bool) -> Result<()> {
+        let paged = self.page.is_some() || self.per_page.is_some();
+        let offset = self.limit.is_some() || self.offset.is_some();
+        let message = if paged && offset {
+            "'page'/'per_page' cannot be combined with 'limit'/'offset'"
+        } else if paged && !pages {
+            "'send_all' pages by 'limit'/'offset'; use 'stream' with 'page'/'per_page'"
+        } else if offset && !offsets {
+            "'stream' pages by 'page'/'per_page'; use 'send_all' with 'limit'/'offset'"
+        } else {
+            return Ok(());
+        };
+
+        Err(Error::Validation(message.to_string()))
+    }
+
+    /// Returns the full request URL, including query parameters, without sending.
+    pub fn url(&self) -> String {
+        self.client.build_url(&self.path())
+    }
+
+    fn path(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(name) = &self.name {
+            params.push(format!("name={}", encode(name)));
+        }
+        if let Some(page) = self.page {
+            params.push(format!("page={}", page));
+        }
+        if let Some(per_page) = self.per_page {
+            params.push(format!("per_page={}", per_page));
+        }
+        if let Some(limit) = self.limit {
+            params.push(format!("limit={}", limit));
+        }
+        if let Some(offset) = self.offset {
+            params.push(format!("offset={}", offset));
+        }
+
+        let query = if params.is_empty() {
+            "".to_string()
         } else {
-            format!("/campuses/{}/groups", self.campus_id)
+            format!("?{}", params.join("&"))
         };
-        self.client.get_json(&path).await
+
+        format!("/campuses/{}/groups{}", self.campus_id, query)
+    }
+
+    /// Fetches every group by following `limit`/`offset` pages.
+    ///
+    /// Starts at the configured offset (or zero) and pages by the configured
+    /// limit (or 100) until a page comes back short. If the server ignores
+    /// paging and returns its whole listing, that single response is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if `page` or `per_page` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use osars::Client;
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let groups = client.groups(1).limit(50).send_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_all(self) -> Result<Vec<Group>> {
+        self.validate(false, true)?;
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT);
+        collect_pages(
+            self.offset.unwrap_or(0),
+            limit,
+            |g: &Group| g.id,
+            |offset| {
+                GroupsQuery {
+                    client: self.client,
+                    campus_id: self.campus_id,
+                    name: self.name.clone(),
+                    page: None,
+                    per_page: None,
+                    limit: Some(limit),
+                    offset: Some(offset),
+                }
+                .send()
+            },
+        )
+        .await
+    }
+
+    /// Streams groups page by page, fetching the next page only when needed.
+    ///
+    /// Starts at the configured page (or the first one) and stops after a page
+    /// shorter than `per_page`, which bounds memory for large rosters. A page
+    /// longer than `per_page` means the server ignored paging and ends the
+    /// stream as well.
+    ///
+    /// The stream yields a single `Error::Validation` if `limit` or `offset`
+    /// is set.
+    pub fn stream(self) -> impl Stream<Item = Result<Group>> + 'a {
+        let mut invalid = self.validate(true, false).err();
+        let client = self.client;
+        let campus_id = self.campus_id;
+        let name = self.name;
+        let per_page = self.per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+        stream::try_unfold(Some(self.page.unwrap_or(1)), move |page| {
+            let name = name.clone();
+            let invalid = invalid.take();
+            async move {
+                if let Some(error) = invalid {
+                    return Err(error);
+                }
+                let Some(page) = page else {
+                    return Ok::<_, Error>(None);
+                };
+                let groups = GroupsQuery {
+                    client,
+                    campus_id,
+                    name,
+                    page: Some(page),
+                    per_page: Some(per_page),
+                    limit: None,
+                    offset: None,
+                }
+                .send()
+                .await?;
+                let next = (groups.len() as u32 == per_page).then_some(page + 1);
+                Ok(Some((
+                    stream::iter(groups.into_iter().map(Ok::<Group, Error>)),
+                    next,
+                )))
+            }
+        })
+        .try_flatten()
     }
 
     pub fn group(self, group_id: u32) -> GroupQuery<'a> {
@@ -72,3 +267,206 @@ impl<'a> GroupQuery<'a> {
         self.schedules().week(week)
     }
 }
+
+impl Client {
+    /// Finds group names that map to more than one group ID within a campus.
+    ///
+    /// Names are compared after trimming, collapsing whitespace and lowercasing,
+    /// so `"ИС-24-1"` and `" ис-24-1 "` are reported together.
+    ///
+    /// # Arguments
+    ///
+    /// * `campus_id` - The ID of the campus
+    ///
+    /// # Returns
+    ///
+    /// Pairs of the first-seen group name and all IDs sharing it, sorted by name.
+    pub async fn duplicate_group_names(&self, campus_id: u32) -> Result<Vec<(String, Vec<u32>)>> {
+        let groups = self.groups(campus_id).send().await?;
+
+        let mut by_name: BTreeMap<String, (String, Vec<u32>)> = BTreeMap::new();
+        for group in groups {
+            let key = group
+                .name
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase();
+            by_name
+                .entry(key)
+                .or_insert_with(|| (group.name.trim().to_string(), Vec::new()))
+                .1
+                .push(group.id);
+        }
+
+        Ok(by_name
+            .into_values()
+            .filter(|(_, ids)| ids.len() > 1)
+            .map(|(name, mut ids)| {
+                ids.sort_unstable();
+                (name, ids)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn test_duplicate_group_names() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 3, "name": "IS-24-1", "campusId": 10},
+                    {"studentGroupId": 4, "name": "IS-24-2", "campusId": 10},
+                    {"studentGroupId": 1, "name": " is-24-1 ", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let duplicates = client.duplicate_group_names(10).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(duplicates, vec![("IS-24-1".to_string(), vec![1, 3])]);
+    }
+
+    #[tokio::test]
+    async fn test_groups_name_is_url_encoded() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::Exact("name=IS%20%26%20AI".into()))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let groups = client.groups(10).name("IS & AI").send().await.unwrap();
+
+        mock.assert_async().await;
+        assert!(groups.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_groups_mixed_paging_rejected() {
+        let client = Client::new("https://api.example.com");
+
+        let sent = client.groups(10).page(2).limit(50).send().await;
+        assert!(matches!(sent, Err(Error::Validation(_))));
+
+        let all = client.groups(10).per_page(20).send_all().await;
+        assert!(matches!(all, Err(Error::Validation(_))));
+
+        let streamed: Vec<_> = client.groups(10).offset(5).stream().collect().await;
+        assert_eq!(streamed.len(), 1);
+        assert!(matches!(streamed[0], Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_groups_stream_follows_pages() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("per_page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 1, "name": "A", "campusId": 10},
+                    {"studentGroupId": 2, "name": "B", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "2".into()),
+                Matcher::UrlEncoded("per_page".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 3, "name": "C", "campusId": 10}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let groups: Vec<Group> = client
+            .groups(10)
+            .per_page(2)
+            .stream()
+            .map(|group| group.unwrap())
+            .collect()
+            .await;
+
+        first.assert_async().await;
+        second.assert_async().await;
+        let ids: Vec<u32> = groups.iter().map(|g| g.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_groups_send_all_follows_offsets() {
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".into(), "2".into()),
+                Matcher::UrlEncoded("offset".into(), "0".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 1, "name": "A", "campusId": 10},
+                    {"studentGroupId": 2, "name": "B", "campusId": 10}]"#,
+            )
+            .create_async()
+            .await;
+        let second = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("limit".into(), "2".into()),
+                Matcher::UrlEncoded("offset".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 3, "name": "C", "campusId": 10}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let groups = client.groups(10).limit(2).send_all().await.unwrap();
+
+        first.assert_async().await;
+        second.assert_async().await;
+        let ids: Vec<u32> = groups.iter().map(|g| g.id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_groups_send_all_when_paging_ignored() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/campuses/10/groups")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                r#"[{"studentGroupId": 1, "name": "A", "campusId": 10},
+                    {"studentGroupId": 2, "name": "B", "campusId": 10}]"#,
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let groups = client.groups(10).limit(2).send_all().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(groups.len(), 2);
+    }
+}
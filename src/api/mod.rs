@@ -1,14 +1,22 @@
 pub mod admin;
+pub mod bulk;
+pub mod campuses;
 pub mod colleges;
+pub mod drift;
 pub mod groups;
+mod paging;
 pub mod parser;
 pub mod schedules;
+pub mod teachers;
 
 pub use admin::AdminApi;
-pub use colleges::CampusQuery;
-pub use colleges::CampusesQuery;
+pub use bulk::{DayLoad, Inconsistency, LoadComparison, SchedulesForQuery, TreeEvent};
+pub use campuses::CampusQuery;
+pub use campuses::CampusesQuery;
 pub use colleges::CollegeQuery;
 pub use colleges::CollegesQuery;
+pub use drift::DriftReport;
 pub use groups::GroupsQuery;
 pub use parser::ParserApi;
 pub use schedules::ScheduleQuery;
+pub use teachers::TeacherScheduleQuery;
@@ -0,0 +1,55 @@
+use crate::error::Result;
+use std::future::Future;
+
+/// Page size used by `send_all` when no limit is set.
+pub(crate) const DEFAULT_LIMIT: u32 = 100;
+
+/// Fetches `limit`/`offset` pages until the listing is exhausted.
+///
+/// Paging stops after a page shorter than `limit`. A server that ignores the
+/// paging parameters is detected when it returns more than `limit` items or
+/// repeats the first item of the listing; the first response is then taken
+/// as the complete list.
+///
+/// # Arguments
+///
+/// * `offset` - The offset of the first page
+/// * `limit` - The page size, at least 1
+/// * `id` - Extracts an item's ID, used to spot repeated pages
+/// * `fetch` - Fetches the page at the given offset
+pub(crate) async fn collect_pages<T, F, Fut>(
+    mut offset: u32,
+    limit: u32,
+    id: fn(&T) -> u32,
+    mut fetch: F,
+) -> Result<Vec<T>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let limit = limit.max(1);
+    let mut items: Vec<T> = Vec::new();
+
+    loop {
+        let page = fetch(offset).await?;
+        let len = page.len() as u32;
+
+        if len > limit {
+            if items.is_empty() {
+                items = page;
+            }
+            break;
+        }
+        if !items.is_empty() && page.first().map(id) == items.first().map(id) {
+            break;
+        }
+
+        items.extend(page);
+        if len < limit {
+            break;
+        }
+        offset += limit;
+    }
+
+    Ok(items)
+}
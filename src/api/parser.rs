@@ -15,27 +15,18 @@ impl ParserApi {
     /// Update groups for a campus
     pub async fn update_groups(&self, request: UpdateGroupsRequest) -> Result<()> {
         let path = "/parser/groups";
-        self.client
-            .client
-            .post_json(path, Some(&request), Some(&self.client.auth))
-            .await
+        self.client.post_json(path, Some(&request)).await
     }
 
     /// Update call schedule
     pub async fn update_calls(&self, request: UpdateCallsRequest) -> Result<()> {
         let path = "/parser/calls";
-        self.client
-            .client
-            .post_json(path, Some(&request), Some(&self.client.auth))
-            .await
+        self.client.post_json(path, Some(&request)).await
     }
 
     /// Add lessons
     pub async fn add_lessons(&self, request: UpdateLessonsRequest) -> Result<()> {
         let path = "/parser/lessons";
-        self.client
-            .client
-            .post_json(path, Some(&request), Some(&self.client.auth))
-            .await
+        self.client.post_json(path, Some(&request)).await
     }
 }
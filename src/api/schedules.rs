@@ -1,6 +1,11 @@
 use crate::models::{Day, Week, Weekday};
-use crate::{Client, Schedule, error::Result};
+use crate::{Client, Schedule, ScheduleDiff, error::Result, schedule_fingerprint};
+use chrono::{Datelike, Local, NaiveDate};
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 
+#[derive(Clone)]
 pub struct ScheduleQuery<'a> {
     client: &'a Client,
     group_id: u32,
@@ -8,6 +13,10 @@ pub struct ScheduleQuery<'a> {
     week: Option<Week>,
     weekday: Option<Weekday>,
     day: Option<Day>,
+    teacher: Option<String>,
+    subject: Option<String>,
+    keep_empty_days: bool,
+    keep_watching_on_error: bool,
 }
 
 impl<'a> ScheduleQuery<'a> {
@@ -19,6 +28,10 @@ impl<'a> ScheduleQuery<'a> {
             week: None,
             weekday: None,
             day: None,
+            teacher: None,
+            subject: None,
+            keep_empty_days: false,
+            keep_watching_on_error: false,
         }
     }
 
@@ -27,33 +40,118 @@ impl<'a> ScheduleQuery<'a> {
         self
     }
 
+    /// Requests the schedule for `date`, sent as `YYYY-MM-DD`.
+    ///
+    /// A typed alternative to [`date`](Self::date).
+    pub fn on(self, date: NaiveDate) -> Self {
+        self.date(&date.format("%Y-%m-%d").to_string())
+    }
+
     pub fn week(mut self, week: Week) -> Self {
         self.week = Some(week);
         self
     }
 
+    /// Narrows the result to a single day of the week.
+    ///
+    /// The weekday is sent as the `weekday` query parameter, and the returned
+    /// schedules are also filtered client-side by their date, so the result
+    /// holds only that day even if the server ignores the parameter. An empty
+    /// vector means the group has no classes on that day.
     pub fn weekday(mut self, weekday: Weekday) -> Self {
         self.weekday = Some(weekday);
         self
     }
 
-    pub fn today(mut self) -> Self {
-        self.day = Some(Day::Today);
+    /// Keeps only lessons taught by `teacher`.
+    ///
+    /// Matching is case-insensitive and ignores surrounding whitespace.
+    /// Co-taught lessons match any of their [`teachers`](crate::Lesson::teachers).
+    /// Days left without lessons are dropped unless
+    /// [`keep_empty_days`](Self::keep_empty_days) is set.
+    pub fn teacher(mut self, teacher: &str) -> Self {
+        self.teacher = Some(teacher.trim().to_lowercase());
+        self
+    }
+
+    /// Keeps only lessons whose title contains `subject`, ignoring case.
+    ///
+    /// Combined with [`teacher`](Self::teacher), a lesson must match both.
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = Some(subject.to_lowercase());
         self
     }
 
-    pub fn tomorrow(mut self) -> Self {
-        self.day = Some(Day::Tomorrow);
+    /// Keeps days whose lessons were all removed by a lesson filter.
+    pub fn keep_empty_days(mut self, keep: bool) -> Self {
+        self.keep_empty_days = keep;
         self
     }
 
+    /// Requests the schedule for a day relative to today.
+    pub fn day(mut self, day: Day) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn today(self) -> Self {
+        self.day(Day::Today)
+    }
+
+    pub fn tomorrow(self) -> Self {
+        self.day(Day::Tomorrow)
+    }
+
+    /// Requests the schedule of the next school day, starting from today.
+    ///
+    /// Today is taken from the local clock and kept if it is a weekday;
+    /// Saturday and Sunday are skipped. Use
+    /// [`next_school_day_from`](Self::next_school_day_from) for another
+    /// weekend or a fixed date.
+    pub fn next_school_day(self) -> Self {
+        self.next_school_day_from(Local::now().date_naive(), &Weekday::WEEKEND)
+    }
+
+    /// Requests the schedule of the first day from `today` not in `weekend`.
+    ///
+    /// If every day is in `weekend`, `today` is requested.
+    ///
+    /// # Arguments
+    ///
+    /// * `today` - The day to start from
+    /// * `weekend` - Days without classes
+    pub fn next_school_day_from(self, today: NaiveDate, weekend: &[Weekday]) -> Self {
+        let date = today
+            .iter_days()
+            .take(7)
+            .find(|date| !weekend.contains(&Weekday::from_chrono(date.weekday())))
+            .unwrap_or(today);
+        self.on(date)
+    }
+
     pub async fn send(self) -> Result<Vec<Schedule>> {
+        let mut schedules: Vec<Schedule> = self.client.get_json(&self.path()).await?;
+
+        if let Some(weekday) = self.weekday {
+            schedules.retain(|schedule| schedule.date.weekday() == weekday.to_chrono());
+        }
+        self.filter_lessons(&mut schedules);
+
+        Ok(schedules)
+    }
+
+    /// Returns the full request URL, including query parameters, without sending.
+    pub fn url(&self) -> String {
+        self.client.build_url(&self.path())
+    }
+
+    fn path(&self) -> String {
         let mut params = Vec::new();
 
-        if let Some(date) = self.date {
+        if let Some(date) = &self.date {
             params.push(format!("date={}", date));
         }
-        if let Some(day) = self.day {
+        if let Some(day) = &self.day {
             params.push(format!("day={}", day));
         }
         if let Some(week) = self.week {
@@ -69,11 +167,156 @@ impl<'a> ScheduleQuery<'a> {
             format!("?{}", params.join("&"))
         };
 
-        let path = format!("/groups/{}/schedules{}", self.group_id, query);
-        self.client.get_json(&path).await
+        format!("/groups/{}/schedules{}", self.group_id, query)
+    }
+
+    fn filter_lessons(&self, schedules: &mut Vec<Schedule>) {
+        if self.teacher.is_none() && self.subject.is_none() {
+            return;
+        }
+
+        for schedule in schedules.iter_mut() {
+            schedule.lessons.retain(|lesson| {
+                self.teacher.as_ref().is_none_or(|teacher| {
+                    lesson
+                        .teachers()
+                        .iter()
+                        .any(|name| name.to_lowercase() == *teacher)
+                }) && self
+                    .subject
+                    .as_ref()
+                    .is_none_or(|subject| lesson.title.to_lowercase().contains(subject))
+            });
+        }
+        if !self.keep_empty_days {
+            schedules.retain(|schedule| !schedule.lessons.is_empty());
+        }
+    }
+
+    /// Sends the query and returns the first schedule, if any.
+    ///
+    /// Meant for single-day queries such as `today()` or `date(...)`, where
+    /// indexing the result would panic on a day without data.
+    pub async fn send_one(self) -> Result<Option<Schedule>> {
+        Ok(self.send().await?.into_iter().next())
+    }
+
+    /// Streams a week's schedules day by day, in date order.
+    ///
+    /// The configured week (or the current one) is fetched with a single
+    /// request, applying the lesson filters, and its days are yielded sorted
+    /// by date. A [`weekday`](Self::weekday) narrows the stream to that day
+    /// as in [`send`](Self::send). Any date or day set on the query is
+    /// dropped, since either would replace the week. Days without data are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use osars::Client;
+    /// use osars::models::Week;
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let mut days = std::pin::pin!(client.schedule(1).week(Week::Next).stream_week());
+    /// while let Some(schedule) = days.try_next().await? {
+    ///     println!("{}: {} lessons", schedule.date, schedule.lessons.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_week(self) -> impl Stream<Item = Result<Schedule>> + 'a {
+        let week = ScheduleQuery {
+            date: None,
+            week: Some(self.week.unwrap_or(Week::Current)),
+            day: None,
+            ..self
+        };
+
+        stream::once(week.send())
+            .map_ok(|mut schedules| {
+                schedules.sort_by_key(|schedule| schedule.date);
+                stream::iter(schedules.into_iter().map(Ok))
+            })
+            .try_flatten()
+    }
+
+    /// Keeps [`watch`](Self::watch) polling after a failed request.
+    ///
+    /// Errors are still yielded; by default the stream ends after the first one.
+    pub fn keep_watching_on_error(mut self, keep: bool) -> Self {
+        self.keep_watching_on_error = keep;
+        self
+    }
+
+    /// Polls the query every `interval` and yields the changes between polls.
+    ///
+    /// The first poll only records a baseline. Later polls are compared with
+    /// the previous result day by day using [`Schedule::diff`], and one
+    /// non-empty diff is yielded per changed date, in date order, paired with
+    /// that date. A day that disappears is reported as all lessons removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Delay between the end of one poll and the next
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use osars::Client;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let mut changes = std::pin::pin!(client.today(1).watch(Duration::from_secs(300)));
+    /// while let Some((date, diff)) = changes.try_next().await? {
+    ///     println!("{}: {} lessons added", date, diff.added.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch(
+        self,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<(NaiveDate, ScheduleDiff)>> + 'a {
+        let keep_going = self.keep_watching_on_error;
+
+        stream::unfold(
+            Some((self, None::<Vec<Schedule>>, false)),
+            move |state| async move {
+                let (query, previous, polled) = state?;
+                if polled {
+                    tokio::time::sleep(interval).await;
+                }
+
+                match query.clone().send().await {
+                    Ok(current) => {
+                        let diffs = previous
+                            .map(|previous| diff_days(&previous, &current))
+                            .unwrap_or_default();
+                        let items: Vec<Result<(NaiveDate, ScheduleDiff)>> =
+                            diffs.into_iter().map(Ok).collect();
+                        Some((items, Some((query, Some(current), true))))
+                    }
+                    Err(e) => Some((vec![Err(e)], keep_going.then_some((query, previous, true)))),
+                }
+            },
+        )
+        .flat_map(stream::iter)
     }
 
-    fn validate(&self) -> Result<()> {
+    /// Checks that the query's parameters can be combined.
+    ///
+    /// [`send`](Self::send) does not call this, so conflicting parameters are
+    /// sent as-is and the server decides how to treat them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if `date` is combined with `week`,
+    /// `weekday` or `day`, or if `day` is combined with `week` or `weekday`.
+    pub fn validate(&self) -> Result<()> {
         if self.date.is_some()
             && (self.week.is_some() || self.weekday.is_some() || self.day.is_some())
         {
@@ -90,51 +333,95 @@ impl<'a> ScheduleQuery<'a> {
 
         Ok(())
     }
+}
 
-    fn add_query_params(self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        let mut request = request;
+/// Diffs two polls of a query date by date, keeping only changed days.
+fn diff_days(previous: &[Schedule], current: &[Schedule]) -> Vec<(NaiveDate, ScheduleDiff)> {
+    let empty = |schedule: &Schedule| Schedule {
+        lessons: Vec::new(),
+        ..schedule.clone()
+    };
 
-        if let Some(date) = self.date {
-            request = request.query(&[("date", date)]);
-        }
+    let mut days: BTreeMap<NaiveDate, (Option<&Schedule>, Option<&Schedule>)> = BTreeMap::new();
+    for schedule in previous {
+        days.entry(schedule.date).or_default().0 = Some(schedule);
+    }
+    for schedule in current {
+        days.entry(schedule.date).or_default().1 = Some(schedule);
+    }
 
-        if let Some(week) = self.week {
-            let week_str = match week {
-                Week::Previous => "previous",
-                Week::Current => "current",
-                Week::Next => "next",
+    days.into_iter()
+        .map(|(date, days)| {
+            let diff = match days {
+                (Some(old), Some(new)) => old.diff(new),
+                (Some(old), None) => old.diff(&empty(old)),
+                (None, Some(new)) => empty(new).diff(new),
+                (None, None) => ScheduleDiff::default(),
             };
-            request = request.query(&[("week", week_str)]);
-        }
-
-        if let Some(weekday) = self.weekday {
-            let weekday_str = match weekday {
-                Weekday::Monday => "monday",
-                Weekday::Tuesday => "tuesday",
-                Weekday::Wednesday => "wednesday",
-                Weekday::Thursday => "thursday",
-                Weekday::Friday => "friday",
-                Weekday::Saturday => "saturday",
-                Weekday::Sunday => "sunday",
-            };
-            request = request.query(&[("weekday", weekday_str)]);
-        }
+            (date, diff)
+        })
+        .filter(|(_, diff)| !diff.is_empty())
+        .collect()
+}
 
-        if let Some(day) = self.day {
-            let day_str = match day {
-                Day::Today => "today",
-                Day::Tomorrow => "tomorrow",
-            };
-            request = request.query(&[("day", day_str)]);
+impl Client {
+    /// Fetches a day's schedule and returns it only if it has changed.
+    ///
+    /// The fetched data is compared with `known_fingerprint` using
+    /// [`schedule_fingerprint`], which lets polling clients skip unchanged data
+    /// without server-side ETag support.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the student group
+    /// * `date` - The date to fetch, as `YYYY-MM-DD`
+    /// * `known_fingerprint` - Fingerprint of the previously seen data
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(None)` if the data matches `known_fingerprint`.
+    pub async fn schedule_if_changed(
+        &self,
+        group_id: u32,
+        date: &str,
+        known_fingerprint: u64,
+    ) -> Result<Option<Vec<Schedule>>> {
+        let schedules = self.schedule(group_id).date(date).send().await?;
+        if schedule_fingerprint(&schedules) == known_fingerprint {
+            Ok(None)
+        } else {
+            Ok(Some(schedules))
         }
+    }
 
-        request
+    /// Lists the distinct subjects a group has in the given week.
+    ///
+    /// Titles are trimmed and inner whitespace is collapsed before deduplication.
+    ///
+    /// # Arguments
+    ///
+    /// * `group_id` - The ID of the student group
+    /// * `week` - Which week to inspect
+    ///
+    /// # Returns
+    ///
+    /// Subject titles sorted alphabetically.
+    pub async fn subjects_for_group(&self, group_id: u32, week: Week) -> Result<Vec<String>> {
+        let schedules = self.schedule(group_id).week(week).send().await?;
+        let subjects: BTreeSet<String> = schedules
+            .iter()
+            .flat_map(|s| &s.lessons)
+            .map(|l| l.title.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|title| !title.is_empty())
+            .collect();
+        Ok(subjects.into_iter().collect())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mockito::{Matcher, Server};
 
     #[test]
     fn test_schedule_query_validation() {
@@ -155,4 +442,585 @@ mod tests {
         let result = query.validate();
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_send_does_not_validate() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("date".into(), "2025-11-17".into()),
+                Matcher::UrlEncoded("week".into(), "current".into()),
+            ]))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let query = client.schedule(7).date("2025-11-17").week(Week::Current);
+
+        assert!(query.validate().is_err());
+        assert!(query.send().await.unwrap().is_empty());
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_schedule_url() {
+        let client = Client::new("https://api.example.com");
+        let query = client.schedule(7).date("2025-11-17");
+
+        assert_eq!(
+            query.url(),
+            "https://api.example.com/groups/7/schedules?date=2025-11-17"
+        );
+    }
+
+    #[test]
+    fn test_on_formats_iso_date() {
+        let client = Client::new("https://api.example.com");
+        let date = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+
+        assert_eq!(
+            client.schedule(7).on(date).url(),
+            "https://api.example.com/groups/7/schedules?date=2025-03-07"
+        );
+    }
+
+    #[test]
+    fn test_day_matches_shortcuts() {
+        let client = Client::new("https://api.example.com");
+
+        assert_eq!(
+            client.schedule(7).day(Day::Tomorrow).url(),
+            client.schedule(7).tomorrow().url()
+        );
+        assert_eq!(
+            client.schedule(7).day(Day::Today).url(),
+            "https://api.example.com/groups/7/schedules?day=today"
+        );
+    }
+
+    #[test]
+    fn test_next_school_day_skips_weekend() {
+        let client = Client::new("https://api.example.com");
+        let saturday = NaiveDate::from_ymd_opt(2025, 11, 22).unwrap();
+
+        let query = client
+            .schedule(7)
+            .next_school_day_from(saturday, &Weekday::WEEKEND);
+
+        assert_eq!(query.date.as_deref(), Some("2025-11-24"));
+    }
+
+    #[test]
+    fn test_next_school_day_custom_weekend() {
+        let client = Client::new("https://api.example.com");
+        let saturday = NaiveDate::from_ymd_opt(2025, 11, 22).unwrap();
+
+        let six_day_week = client
+            .schedule(7)
+            .next_school_day_from(saturday, &[Weekday::Sunday]);
+        let friday_off = client.schedule(7).next_school_day_from(
+            saturday - chrono::Days::new(1),
+            &[Weekday::Friday, Weekday::Saturday, Weekday::Sunday],
+        );
+
+        assert_eq!(six_day_week.date.as_deref(), Some("2025-11-22"));
+        assert_eq!(friday_off.date.as_deref(), Some("2025-11-24"));
+    }
+
+    #[tokio::test]
+    async fn test_on_sends_date_param() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-15".into()))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        client
+            .schedule(7)
+            .on(NaiveDate::from_ymd_opt(2025, 11, 15).unwrap())
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_one_returns_first_schedule() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("day".into(), "today".into()))
+            .with_status(200)
+            .with_body(r#"[{"groupId": 7, "date": "2025-11-17", "lessons": []}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedule = client.today(7).send_one().await.unwrap().unwrap();
+
+        assert_eq!(schedule.group_id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_send_one_empty_response() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-22".into()))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedule = client
+            .schedule(7)
+            .date("2025-11-22")
+            .send_one()
+            .await
+            .unwrap();
+
+        assert!(schedule.is_none());
+    }
+
+    async fn two_teacher_week(server: &mut Server) -> mockito::Mock {
+        server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "current".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-17", "lessons": [
+                    {"title": "Math", "cabinet": "101", "teacher": "John Smith", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"},
+                    {"title": "Physics Lab", "cabinet": "102", "teacher": "Ann Lee", "order": 2,
+                     "startTime": "10:40:00", "endTime": "12:10:00"},
+                    {"title": "Math Practice", "cabinet": "101", "teacher": " john smith ", "order": 3,
+                     "startTime": "12:40:00", "endTime": "14:10:00"},
+                    {"title": "Seminar", "cabinet": "103", "teacher": "Ann Lee, John Smith", "order": 4,
+                     "startTime": "14:20:00", "endTime": "15:50:00"}
+                ]},
+                {"groupId": 7, "date": "2025-11-18", "lessons": [
+                    {"title": "Physics", "cabinet": "102", "teacher": "Ann Lee", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_teacher_filter() {
+        let mut server = Server::new_async().await;
+        let _mock = two_teacher_week(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .teacher("John Smith")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schedules.len(), 1);
+        let orders: Vec<u32> = schedules[0].lessons.iter().map(|l| l.order).collect();
+        assert_eq!(orders, [1, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_teacher_filter_keeps_empty_days() {
+        let mut server = Server::new_async().await;
+        let _mock = two_teacher_week(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .teacher("john smith")
+            .keep_empty_days(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schedules.len(), 2);
+        assert!(schedules[1].lessons.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subject_filter() {
+        let mut server = Server::new_async().await;
+        let _mock = two_teacher_week(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .subject("physics")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schedules.len(), 2);
+        assert_eq!(schedules[0].lessons[0].title, "Physics Lab");
+        assert_eq!(schedules[1].lessons[0].title, "Physics");
+    }
+
+    #[tokio::test]
+    async fn test_subject_filter_no_match() {
+        let mut server = Server::new_async().await;
+        let _mock = two_teacher_week(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .subject("chemistry")
+            .send()
+            .await
+            .unwrap();
+
+        assert!(schedules.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subject_and_teacher_filters_combine() {
+        let mut server = Server::new_async().await;
+        let _mock = two_teacher_week(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .teacher("John Smith")
+            .subject("practice")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].lessons.len(), 1);
+        assert_eq!(schedules[0].lessons[0].order, 3);
+    }
+
+    async fn week_mock(server: &mut Server) -> mockito::Mock {
+        server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("week".into(), "current".into()),
+                Matcher::UrlEncoded("weekday".into(), "monday".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-17", "lessons": []},
+                    {"groupId": 7, "date": "2025-11-18", "lessons": []}]"#,
+            )
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_weekday_isolates_day() {
+        let mut server = Server::new_async().await;
+        let mock = week_mock(&mut server).await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .week(Week::Current)
+            .weekday(Weekday::Monday)
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].date.to_string(), "2025-11-17");
+    }
+
+    #[tokio::test]
+    async fn test_weekday_without_classes_is_empty() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("weekday".into(), "sunday".into()))
+            .with_status(200)
+            .with_body(r#"[{"groupId": 7, "date": "2025-11-17", "lessons": []}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .schedule(7)
+            .weekday(Weekday::Sunday)
+            .send()
+            .await
+            .unwrap();
+
+        assert!(schedules.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stream_week_yields_days_in_order() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::Exact("week=next".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-26", "lessons": []},
+                    {"groupId": 7, "date": "2025-11-24", "lessons": []},
+                    {"groupId": 7, "date": "2025-11-25", "lessons": []}]"#,
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules: Vec<Schedule> = client
+            .schedule(7)
+            .week(Week::Next)
+            .today()
+            .stream_week()
+            .try_collect()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let days: Vec<u32> = schedules.iter().map(|s| s.date.day()).collect();
+        assert_eq!(days, [24, 25, 26]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_week_keeps_weekday_filter() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("week".into(), "next".into()),
+                Matcher::UrlEncoded("weekday".into(), "tuesday".into()),
+            ]))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-24", "lessons": []},
+                    {"groupId": 7, "date": "2025-11-25", "lessons": []}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules: Vec<Schedule> = client
+            .schedule(7)
+            .week(Week::Next)
+            .weekday(Weekday::Tuesday)
+            .stream_week()
+            .try_collect()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let days: Vec<u32> = schedules.iter().map(|s| s.date.day()).collect();
+        assert_eq!(days, [25]);
+    }
+
+    #[tokio::test]
+    async fn test_watch_emits_diff_when_schedule_changes() {
+        let polls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = polls.clone();
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("day".into(), "today".into()))
+            .with_status(200)
+            .with_body_from_request(move |_| {
+                let lesson = r#"{"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                                 "startTime": "09:00:00", "endTime": "10:30:00"}"#;
+                let lessons = match counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                    0 => String::new(),
+                    _ => lesson.to_string(),
+                };
+                format!(
+                    r#"[{{"groupId": 7, "date": "2025-11-17", "lessons": [{}]}}]"#,
+                    lessons
+                )
+                .into_bytes()
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let diffs: Vec<(NaiveDate, ScheduleDiff)> = tokio::time::timeout(
+            Duration::from_secs(5),
+            client
+                .today(7)
+                .watch(Duration::from_millis(10))
+                .take(1)
+                .map(|diff| diff.unwrap())
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].0, NaiveDate::from_ymd_opt(2025, 11, 17).unwrap());
+        assert_eq!(diffs[0].1.added[0].title, "Math");
+        assert!(diffs[0].1.removed.is_empty());
+        assert!(polls.load(std::sync::atomic::Ordering::SeqCst) >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_watch_reports_each_changed_date() {
+        let polls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = polls.clone();
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "current".into()))
+            .with_status(200)
+            .with_body_from_request(move |_| {
+                let (monday, tuesday) =
+                    match counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                        0 => ("Math", "Physics"),
+                        _ => ("Art", "Music"),
+                    };
+                format!(
+                    r#"[{{"groupId": 7, "date": "2025-11-17", "lessons": [
+                        {{"title": "{}", "cabinet": "101", "teacher": "John", "order": 1,
+                         "startTime": "09:00:00", "endTime": "10:30:00"}}]}},
+                       {{"groupId": 7, "date": "2025-11-18", "lessons": [
+                        {{"title": "{}", "cabinet": "101", "teacher": "John", "order": 1,
+                         "startTime": "09:00:00", "endTime": "10:30:00"}}]}}]"#,
+                    monday, tuesday
+                )
+                .into_bytes()
+            })
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let diffs: Vec<(NaiveDate, ScheduleDiff)> = tokio::time::timeout(
+            Duration::from_secs(5),
+            client
+                .schedule(7)
+                .week(Week::Current)
+                .watch(Duration::from_millis(10))
+                .take(2)
+                .map(|diff| diff.unwrap())
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(polls.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(diffs[0].0, NaiveDate::from_ymd_opt(2025, 11, 17).unwrap());
+        assert_eq!(diffs[0].1.modified[0].new.title, "Art");
+        assert_eq!(diffs[1].0, NaiveDate::from_ymd_opt(2025, 11, 18).unwrap());
+        assert_eq!(diffs[1].1.modified[0].new.title, "Music");
+    }
+
+    #[tokio::test]
+    async fn test_watch_stops_on_error() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let items: Vec<Result<(NaiveDate, ScheduleDiff)>> = client
+            .today(7)
+            .watch(Duration::from_millis(10))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_if_changed_unchanged() {
+        let body = r#"[{"groupId": 7, "date": "2025-11-17", "lessons": [{
+            "title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+            "startTime": "09:00:00", "endTime": "10:30:00"
+        }]}]"#;
+        let known: Vec<Schedule> = serde_json::from_str(body).unwrap();
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-17".into()))
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let result = client
+            .schedule_if_changed(7, "2025-11-17", schedule_fingerprint(&known))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_if_changed_changed() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-17".into()))
+            .with_status(200)
+            .with_body(r#"[{"groupId": 7, "date": "2025-11-17", "lessons": []}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let result = client
+            .schedule_if_changed(7, "2025-11-17", schedule_fingerprint(&[]))
+            .await
+            .unwrap();
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_subjects_for_group_dedups() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "current".into()))
+            .with_status(200)
+            .with_body(
+                r#"[{"groupId": 7, "date": "2025-11-17", "lessons": [
+                    {"title": "Math", "cabinet": "101", "teacher": "John", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"},
+                    {"title": " Physics  Lab ", "cabinet": "102", "teacher": "Ann", "order": 2,
+                     "startTime": "10:40:00", "endTime": "12:10:00"}
+                ]},
+                {"groupId": 7, "date": "2025-11-18", "lessons": [
+                    {"title": "Physics Lab", "cabinet": "102", "teacher": "Ann", "order": 1,
+                     "startTime": "09:00:00", "endTime": "10:30:00"},
+                    {"title": "Math ", "cabinet": "101", "teacher": "John", "order": 2,
+                     "startTime": "10:40:00", "endTime": "12:10:00"}
+                ]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let subjects = client.subjects_for_group(7, Week::Current).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(subjects, ["Math", "Physics Lab"]);
+    }
 }
@@ -0,0 +1,241 @@
+use crate::models::{Day, Week};
+use crate::{Client, Schedule, error::Result};
+use chrono::NaiveDate;
+use urlencoding::encode;
+
+pub struct TeacherScheduleQuery<'a> {
+    client: &'a Client,
+    teacher_name: String,
+    date: Option<String>,
+    week: Option<Week>,
+    day: Option<Day>,
+}
+
+impl<'a> TeacherScheduleQuery<'a> {
+    pub fn new(client: &'a Client, teacher_name: &str) -> Self {
+        Self {
+            client,
+            teacher_name: teacher_name.trim().to_string(),
+            date: None,
+            week: None,
+            day: None,
+        }
+    }
+
+    pub fn date(mut self, date: &str) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    /// Requests the schedule for `date`, sent as `YYYY-MM-DD`.
+    ///
+    /// A typed alternative to [`date`](Self::date).
+    pub fn on(self, date: NaiveDate) -> Self {
+        self.date(&date.format("%Y-%m-%d").to_string())
+    }
+
+    pub fn week(mut self, week: Week) -> Self {
+        self.week = Some(week);
+        self
+    }
+
+    pub fn today(mut self) -> Self {
+        self.day = Some(Day::Today);
+        self
+    }
+
+    pub fn tomorrow(mut self) -> Self {
+        self.day = Some(Day::Tomorrow);
+        self
+    }
+
+    /// Sends the query and returns the teacher's schedules.
+    ///
+    /// Only lessons taught by the teacher are kept, compared case-insensitively
+    /// against each name in [`Lesson::teachers`](crate::Lesson::teachers), and
+    /// days left without lessons are dropped. Parameters are not checked;
+    /// call [`validate`](Self::validate) first to reject conflicting ones.
+    pub async fn send(self) -> Result<Vec<Schedule>> {
+        let mut schedules: Vec<Schedule> = self.client.get_json(&self.path()).await?;
+
+        let name = self.teacher_name.to_lowercase();
+        for schedule in schedules.iter_mut() {
+            schedule.lessons.retain(|lesson| {
+                lesson
+                    .teachers()
+                    .iter()
+                    .any(|teacher| teacher.to_lowercase() == name)
+            });
+        }
+        schedules.retain(|schedule| !schedule.lessons.is_empty());
+
+        Ok(schedules)
+    }
+
+    /// Returns the full request URL, including query parameters, without sending.
+    pub fn url(&self) -> String {
+        self.client.build_url(&self.path())
+    }
+
+    fn path(&self) -> String {
+        let mut params = Vec::new();
+
+        if let Some(date) = &self.date {
+            params.push(format!("date={}", date));
+        }
+        if let Some(day) = &self.day {
+            params.push(format!("day={}", day));
+        }
+        if let Some(week) = self.week {
+            params.push(format!("week={}", week));
+        }
+
+        let query = if params.is_empty() {
+            "".to_string()
+        } else {
+            format!("?{}", params.join("&"))
+        };
+
+        format!("/teachers/{}/schedule{}", encode(&self.teacher_name), query)
+    }
+
+    /// Checks that the query's parameters can be combined.
+    ///
+    /// [`send`](Self::send) does not call this, so conflicting parameters are
+    /// sent as-is and the server decides how to treat them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if more than one of `date`, `week` and a
+    /// day is set.
+    pub fn validate(&self) -> Result<()> {
+        let set = [self.date.is_some(), self.week.is_some(), self.day.is_some()];
+        if set.iter().filter(|&&set| set).count() > 1 {
+            return Err(crate::error::Error::Validation(
+                "parameters 'date', 'week' and 'day' cannot be combined".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    const BODY: &str = r#"[{"groupId": 7, "date": "2025-11-17", "lessons": [
+        {"title": "Math", "cabinet": "101", "teacher": "John Smith", "order": 1,
+         "startTime": "09:00:00", "endTime": "10:30:00"},
+        {"title": "Physics", "cabinet": "102", "teacher": "Ann Lee, john smith", "order": 2,
+         "startTime": "10:40:00", "endTime": "12:10:00"},
+        {"title": "History", "cabinet": "103", "teacher": "Ann Lee", "order": 3,
+         "startTime": "12:40:00", "endTime": "14:10:00"}
+    ]}]"#;
+
+    #[tokio::test]
+    async fn test_teacher_schedule_today() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/teachers/John%20Smith/schedule")
+            .match_query(Matcher::UrlEncoded("day".into(), "today".into()))
+            .with_status(200)
+            .with_body(BODY)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .teacher_schedule("John Smith")
+            .today()
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(schedules.len(), 1);
+        let orders: Vec<u32> = schedules[0].lessons.iter().map(|l| l.order).collect();
+        assert_eq!(orders, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_teacher_schedule_date() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/teachers/Ann%20Lee/schedule")
+            .match_query(Matcher::UrlEncoded("date".into(), "2025-11-17".into()))
+            .with_status(200)
+            .with_body(BODY)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .teacher_schedule("Ann Lee")
+            .date("2025-11-17")
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let titles: Vec<&str> = schedules[0]
+            .lessons
+            .iter()
+            .map(|l| l.title.as_str())
+            .collect();
+        assert_eq!(titles, ["Physics", "History"]);
+    }
+
+    #[test]
+    fn test_teacher_schedule_validation() {
+        let client = Client::new("https://api.example.com");
+        let query = client
+            .teacher_schedule("Ann Lee")
+            .date("2025-11-17")
+            .week(Week::Current);
+
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_teacher_schedule_url() {
+        let client = Client::new("https://api.example.com");
+        let query = client
+            .teacher_schedule("Ann Lee")
+            .on(NaiveDate::from_ymd_opt(2025, 11, 17).unwrap());
+
+        assert!(query.validate().is_ok());
+        assert_eq!(
+            query.url(),
+            "https://api.example.com/teachers/Ann%20Lee/schedule?date=2025-11-17"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_does_not_validate() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/teachers/Ann%20Lee/schedule")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("date".into(), "2025-11-17".into()),
+                Matcher::UrlEncoded("week".into(), "current".into()),
+            ]))
+            .with_status(200)
+            .with_body(BODY)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let schedules = client
+            .teacher_schedule("Ann Lee")
+            .date("2025-11-17")
+            .week(Week::Current)
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(schedules[0].lessons.len(), 2);
+    }
+}
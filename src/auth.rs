@@ -1,15 +1,96 @@
 use crate::AdminApi;
 use crate::ParserApi;
-use std::sync::Arc;
+use crate::error::{Error, Result};
+use futures::future::BoxFuture;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+const TOKEN_VAR: &str = "OSARS_TOKEN";
+const API_KEY_VAR: &str = "OSARS_API_KEY";
+const API_KEY_HEADER: &str = "X-Api-Key";
 
 #[derive(Debug, Clone)]
 pub struct Auth {
     pub token: Option<String>,
+    credential: Option<Credential>,
+}
+
+/// Credentials sent in addition to, or instead of, the bearer token.
+#[derive(Debug, Clone)]
+enum Credential {
+    Header { name: String, value: String },
+    Query { name: String, value: String },
+    Basic { username: String, password: String },
 }
 
 impl Auth {
     pub fn new(token: Option<String>) -> Self {
-        Self { token }
+        Self {
+            token,
+            credential: None,
+        }
+    }
+
+    /// Creates credentials sent as `Authorization: Bearer <token>`.
+    pub fn bearer(token: &str) -> Self {
+        Self::new(Some(token.to_string()))
+    }
+
+    /// Creates credentials sending a static API key in the header `name`.
+    pub fn api_key_header(name: &str, value: &str) -> Self {
+        Self {
+            token: None,
+            credential: Some(Credential::Header {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    /// Creates credentials appending a static API key as the query parameter `name`.
+    pub fn api_key_query(name: &str, value: &str) -> Self {
+        Self {
+            token: None,
+            credential: Some(Credential::Query {
+                name: name.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    /// Creates HTTP Basic credentials.
+    pub fn basic(username: &str, password: &str) -> Self {
+        Self {
+            token: None,
+            credential: Some(Credential::Basic {
+                username: username.to_string(),
+                password: password.to_string(),
+            }),
+        }
+    }
+
+    /// Reads credentials from the environment.
+    ///
+    /// `OSARS_TOKEN` is used as a bearer token; otherwise `OSARS_API_KEY` is
+    /// sent in the `X-Api-Key` header. Empty variables are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if neither variable is set.
+    pub fn from_env() -> Result<Self> {
+        let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+        if let Some(token) = var(TOKEN_VAR) {
+            Ok(Self::bearer(&token))
+        } else if let Some(key) = var(API_KEY_VAR) {
+            Ok(Self::api_key_header(API_KEY_HEADER, &key))
+        } else {
+            Err(Error::Validation(format!(
+                "Neither {} nor {} is set",
+                TOKEN_VAR, API_KEY_VAR
+            )))
+        }
     }
 
     pub fn with_token(mut self, token: &str) -> Self {
@@ -26,10 +107,19 @@ impl Auth {
         &self,
         request: reqwest::RequestBuilder,
     ) -> reqwest::RequestBuilder {
-        if let Some(token) = &self.token {
+        let request = if let Some(token) = &self.token {
             request.bearer_auth(token)
         } else {
             request
+        };
+
+        match &self.credential {
+            Some(Credential::Header { name, value }) => request.header(name, value),
+            Some(Credential::Query { name, value }) => request.query(&[(name, value)]),
+            Some(Credential::Basic { username, password }) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
         }
     }
 }
@@ -38,6 +128,21 @@ impl Auth {
 pub struct AuthenticatedClient {
     pub client: crate::Client,
     pub auth: Auth,
+    refresher: Option<Arc<TokenRefresher>>,
+}
+
+type RefreshFn = dyn Fn() -> BoxFuture<'static, Result<String>> + Send + Sync;
+
+/// Produces fresh bearer tokens and remembers the latest one.
+struct TokenRefresher {
+    refresh: Box<RefreshFn>,
+    token: Mutex<Option<String>>,
+}
+
+impl fmt::Debug for TokenRefresher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TokenRefresher")
+    }
 }
 
 impl AuthenticatedClient {
@@ -45,14 +150,101 @@ impl AuthenticatedClient {
         Self {
             client,
             auth: Auth::new(None),
+            refresher: None,
         }
     }
 
+    /// Refreshes the bearer token when a request is rejected with 401.
+    ///
+    /// On a 401 response `refresh` is called once, the returned token
+    /// replaces the current one for this client and all its clones, and the
+    /// request is retried. A second 401 is returned as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .authenticated()
+    ///     .with_token("expired")
+    ///     .with_token_refresh(|| async { Ok("fresh".to_string()) });
+    /// ```
+    pub fn with_token_refresh<F, Fut>(mut self, refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String>> + Send + 'static,
+    {
+        self.refresher = Some(Arc::new(TokenRefresher {
+            refresh: Box::new(move || Box::pin(refresh())),
+            token: Mutex::new(None),
+        }));
+        self
+    }
+
     pub fn with_token(mut self, token: &str) -> Self {
         self.auth = self.auth.with_token(token);
         self
     }
 
+    /// Replaces the credentials sent with every request.
+    pub fn with_auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Credentials for the next request, including any refreshed token.
+    fn current_auth(&self) -> Auth {
+        let refreshed = self
+            .refresher
+            .as_ref()
+            .and_then(|refresher| refresher.token.lock().unwrap().clone());
+        match refreshed {
+            Some(token) => self.auth.clone().with_token(&token),
+            None => self.auth.clone(),
+        }
+    }
+
+    /// Runs `send` with the current credentials, refreshing the token once on 401.
+    async fn with_refresh<T, F, Fut>(&self, send: F) -> Result<T>
+    where
+        F: Fn(Auth) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let result = send(self.current_auth()).await;
+        let Some(refresher) = &self.refresher else {
+            return result;
+        };
+
+        match result {
+            Err(Error::Unauthorized { .. }) => {
+                let token = (refresher.refresh)().await?;
+                *refresher.token.lock().unwrap() = Some(token);
+                send(self.current_auth()).await
+            }
+            result => result,
+        }
+    }
+
+    pub(crate) async fn post_json<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.with_refresh(
+            |auth| async move { self.client.post_json(path, body, Some(&auth)).await },
+        )
+        .await
+    }
+
+    pub(crate) async fn delete_json<T>(&self, path: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.with_refresh(|auth| async move { self.client.delete_json(path, Some(&auth)).await })
+            .await
+    }
+
     pub fn admin(&self) -> AdminApi {
         AdminApi::new(self.clone())
     }
@@ -61,3 +253,220 @@ impl AuthenticatedClient {
         ParserApi::new(self.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Client, CreateParserRequest};
+    use mockito::Server;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn parser_request() -> CreateParserRequest {
+        CreateParserRequest {
+            college_name: "Test College".to_string(),
+            campus_names: vec![],
+        }
+    }
+
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn set_env(token: Option<&str>, api_key: Option<&str>) {
+        for (name, value) in [(TOKEN_VAR, token), (API_KEY_VAR, api_key)] {
+            // SAFETY: tests touching these variables hold ENV_LOCK.
+            unsafe {
+                match value {
+                    Some(value) => std::env::set_var(name, value),
+                    None => std::env::remove_var(name),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_auth_from_env() {
+        let _guard = ENV_LOCK.lock().unwrap();
+
+        set_env(Some("secret"), Some("key"));
+        let auth = Auth::from_env().unwrap();
+        assert_eq!(auth.token.as_deref(), Some("secret"));
+        assert!(auth.credential.is_none());
+
+        set_env(None, Some("key"));
+        let auth = Auth::from_env().unwrap();
+        assert!(auth.token.is_none());
+        assert!(matches!(
+            auth.credential,
+            Some(Credential::Header { ref name, ref value }) if name == API_KEY_HEADER && value == "key"
+        ));
+
+        set_env(None, None);
+        assert!(matches!(Auth::from_env(), Err(Error::Validation(_))));
+        assert!(
+            Client::new("https://api.example.com")
+                .authenticated_from_env()
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_api_key_header() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/admin/parser/3")
+            .match_header("x-api-key", "secret")
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .authenticated()
+            .with_auth(Auth::api_key_header("X-Api-Key", "secret"));
+        client.admin().delete_parser(3).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_api_key_query() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/admin/parser/3")
+            .match_query(mockito::Matcher::UrlEncoded("key".into(), "secret".into()))
+            .match_header("authorization", mockito::Matcher::Missing)
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .authenticated()
+            .with_auth(Auth::api_key_query("key", "secret"));
+        client.admin().delete_parser(3).await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_basic_auth() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/admin/parser")
+            // base64("admin:p@ss")
+            .match_header("authorization", "Basic YWRtaW46cEBzcw==")
+            .with_status(200)
+            .with_body(r#"{"token": "parser-token"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .authenticated()
+            .with_auth(Auth::basic("admin", "p@ss"));
+        client
+            .admin()
+            .create_parser(parser_request())
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    fn counting_refresh(
+        client: AuthenticatedClient,
+        calls: &Arc<AtomicUsize>,
+    ) -> AuthenticatedClient {
+        let calls = calls.clone();
+        client.with_token_refresh(move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Ok("fresh".to_string()) }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_token_refresh_on_401() {
+        let mut server = Server::new_async().await;
+        let expired = server
+            .mock("POST", "/admin/parser")
+            .match_header("authorization", "Bearer expired")
+            .with_status(401)
+            .expect(1)
+            .create_async()
+            .await;
+        let fresh = server
+            .mock("POST", "/admin/parser")
+            .match_header("authorization", "Bearer fresh")
+            .with_status(200)
+            .with_body(r#"{"token": "parser-token"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = counting_refresh(
+            Client::new(&server.url())
+                .authenticated()
+                .with_token("expired"),
+            &calls,
+        );
+        client
+            .admin()
+            .create_parser(parser_request())
+            .await
+            .unwrap();
+        client
+            .admin()
+            .create_parser(parser_request())
+            .await
+            .unwrap();
+
+        expired.assert_async().await;
+        fresh.assert_async().await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_second_401_propagates() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("DELETE", "/admin/parser/3")
+            .with_status(401)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = counting_refresh(
+            Client::new(&server.url())
+                .authenticated()
+                .with_token("expired"),
+            &calls,
+        );
+        let result = client.admin().delete_parser(3).await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(Error::Unauthorized { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bearer_auth_on_post() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/admin/parser")
+            .match_header("authorization", "Bearer secret")
+            .with_status(200)
+            .with_body(r#"{"token": "parser-token"}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .authenticated()
+            .with_auth(Auth::bearer("secret"));
+        let response = client
+            .admin()
+            .create_parser(parser_request())
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.token, "parser-token");
+    }
+}
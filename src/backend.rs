@@ -0,0 +1,227 @@
+use crate::error::{Error, Result};
+use futures::future::BoxFuture;
+use reqwest::Method;
+use reqwest::header::HeaderMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An HTTP request as handed to an [`HttpBackend`].
+///
+/// The client fills in everything it would send over the network:
+/// authentication, default headers, the `User-Agent`, `If-None-Match` for
+/// cached responses and the JSON body.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// Request method
+    pub method: Method,
+    /// Full request URL, including query parameters
+    pub url: String,
+    /// Request headers
+    pub headers: HeaderMap,
+    /// Request body, if any
+    pub body: Option<Vec<u8>>,
+    /// Per-request timeout configured on the client
+    pub timeout: Option<Duration>,
+}
+
+impl HttpRequest {
+    fn from_reqwest(request: reqwest::Request) -> Self {
+        Self {
+            method: request.method().clone(),
+            url: request.url().to_string(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(<[u8]>::to_vec),
+            timeout: request.timeout().copied(),
+        }
+    }
+}
+
+/// An HTTP response returned by an [`HttpBackend`].
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers, used for `ETag` and `Retry-After`
+    pub headers: HeaderMap,
+    /// Response body as text
+    pub body: String,
+}
+
+/// Transport used by a [`Client`](crate::Client) to perform HTTP requests.
+///
+/// Every request goes through a backend. By default the client uses its
+/// `reqwest::Client`; installing another backend with
+/// [`Client::with_backend`](crate::Client::with_backend) lets tests serve
+/// canned responses from memory.
+pub trait HttpBackend: Send + Sync {
+    /// Performs `request` and resolves to the response.
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+impl HttpBackend for reqwest::Client {
+    fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let mut builder = self
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Box::pin(async move {
+            let response = builder.send().await.map_err(Error::Reqwest)?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response.text().await.map_err(Error::Reqwest)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Backend(pub(crate) Arc<dyn HttpBackend>);
+
+impl fmt::Debug for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Backend")
+    }
+}
+
+/// Converts a built reqwest request into the form passed to backends.
+pub(crate) fn to_http_request(request: reqwest::RequestBuilder) -> Result<HttpRequest> {
+    let request = request.build().map_err(Error::Reqwest)?;
+    Ok(HttpRequest::from_reqwest(request))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Auth, Client};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Canned {
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl HttpBackend for Canned {
+        fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let response = match request.method {
+                Method::GET => HttpResponse {
+                    status: 200,
+                    body:
+                        r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#
+                            .to_string(),
+                    ..HttpResponse::default()
+                },
+                Method::DELETE => HttpResponse {
+                    status: 404,
+                    body: r#"{"error": "missing"}"#.to_string(),
+                    ..HttpResponse::default()
+                },
+                _ => HttpResponse {
+                    status: 201,
+                    ..HttpResponse::default()
+                },
+            };
+            self.requests.lock().unwrap().push(request);
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_serves_colleges() {
+        let backend = Arc::new(Canned::default());
+        let client = Client::new("https://api.example.com").with_backend(backend.clone());
+
+        let colleges = client.colleges().send().await.unwrap();
+
+        assert_eq!(colleges[0].name, "Test College");
+        let requests = backend.requests.lock().unwrap();
+        assert_eq!(requests[0].url, "https://api.example.com/colleges");
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_error_status() {
+        let client =
+            Client::new("https://api.example.com").with_backend(Arc::new(Canned::default()));
+
+        let result: Result<()> = client.delete_json("/items/1", None).await;
+
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_receives_client_headers() {
+        let backend = Arc::new(Canned::default());
+        let client = Client::new("https://api.example.com")
+            .with_default_header("X-Tenant", "tyumen")
+            .with_timeout(Duration::from_secs(5))
+            .with_backend(backend.clone());
+
+        client
+            .authenticated()
+            .with_auth(Auth::bearer("secret"))
+            .parser()
+            .update_calls(crate::UpdateCallsRequest { calls: vec![] })
+            .await
+            .unwrap();
+
+        let requests = backend.requests.lock().unwrap();
+        let request = &requests[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.headers["authorization"], "Bearer secret");
+        assert_eq!(request.headers["x-tenant"], "tyumen");
+        assert_eq!(
+            request.headers["user-agent"],
+            concat!("osars/", env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(request.headers["content-type"], "application/json");
+        assert_eq!(request.body.as_deref(), Some(br#"{"calls":[]}"#.as_slice()));
+        assert_eq!(request.timeout, Some(Duration::from_secs(5)));
+    }
+
+    struct Revalidating;
+
+    impl HttpBackend for Revalidating {
+        fn execute(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let mut response = HttpResponse::default();
+            if request
+                .headers
+                .get("if-none-match")
+                .is_some_and(|v| v == "\"v1\"")
+            {
+                response.status = 304;
+            } else {
+                response.status = 200;
+                response.headers.insert("etag", "\"v1\"".parse().unwrap());
+                response.body = "[]".to_string();
+            }
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fake_backend_revalidates_with_etag() {
+        let client = Client::new("https://api.example.com")
+            .with_cache(Duration::ZERO)
+            .with_backend(Arc::new(Revalidating));
+
+        let (_, first) = client.colleges().send_with_meta().await.unwrap();
+        let (_, second) = client.colleges().send_with_meta().await.unwrap();
+
+        assert!(!first.from_cache);
+        assert_eq!(second.status, 304);
+        assert!(second.from_cache);
+    }
+}
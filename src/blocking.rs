@@ -0,0 +1,288 @@
+//! A blocking client for consumers that do not run inside an async runtime.
+//!
+//! Rather than using `reqwest::blocking`, every call drives the async
+//! [`crate::Client`] on a private runtime, so retries, caching, rate limiting,
+//! custom backends and the rest of the client configuration behave exactly as
+//! in the async API. Calling these methods from within an async runtime
+//! panics; use the async client there instead.
+
+use crate::models::Week;
+use crate::{Campus, College, Group, Result, Schedule, api};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// A blocking client for the educational schedule API.
+///
+/// # Examples
+///
+/// ```no_run
+/// use osars::blocking::Client;
+///
+/// let client = Client::new("https://api.example.com").with_college(1);
+/// let campuses = client.campuses()?.send()?;
+/// # Ok::<(), osars::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a new blocking client with the specified base URL.
+    pub fn new(base_url: &str) -> Self {
+        Self::from_async(crate::Client::new(base_url))
+    }
+
+    /// Wraps a configured async client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be created.
+    pub fn from_async(inner: crate::Client) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to build blocking runtime");
+        Self {
+            inner,
+            runtime: Arc::new(runtime),
+        }
+    }
+
+    /// Sets a default college ID for subsequent queries.
+    pub fn with_college(mut self, college_id: u32) -> Self {
+        self.inner = self.inner.with_college(college_id);
+        self
+    }
+
+    /// Creates a query to list all colleges.
+    pub fn colleges(&self) -> CollegesQuery<'_> {
+        CollegesQuery {
+            runtime: &self.runtime,
+            inner: self.inner.colleges(),
+        }
+    }
+
+    /// Creates a query for the default college.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if no default college is set.
+    pub fn college(&self) -> Result<CollegeQuery<'_>> {
+        Ok(CollegeQuery {
+            runtime: &self.runtime,
+            inner: self.inner.college()?,
+        })
+    }
+
+    /// Creates a query to list campuses for the default college.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if no default college is set.
+    pub fn campuses(&self) -> Result<CampusesQuery<'_>> {
+        Ok(CampusesQuery {
+            runtime: &self.runtime,
+            inner: self.inner.campuses()?,
+        })
+    }
+
+    /// Creates a query to list groups for a campus.
+    pub fn groups(&self, campus_id: u32) -> GroupsQuery<'_> {
+        GroupsQuery {
+            runtime: &self.runtime,
+            inner: self.inner.groups(campus_id),
+        }
+    }
+
+    /// Creates a query for a group's schedule.
+    pub fn schedule(&self, group_id: u32) -> ScheduleQuery<'_> {
+        ScheduleQuery {
+            runtime: &self.runtime,
+            inner: self.inner.schedule(group_id),
+        }
+    }
+}
+
+pub struct CollegesQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::CollegesQuery<'a>,
+}
+
+impl<'a> CollegesQuery<'a> {
+    pub fn name(mut self, name: &str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    pub fn send(self) -> Result<Vec<College>> {
+        self.runtime.block_on(self.inner.send())
+    }
+
+    pub fn college(self, college_id: u32) -> CollegeQuery<'a> {
+        CollegeQuery {
+            runtime: self.runtime,
+            inner: self.inner.college(college_id),
+        }
+    }
+}
+
+pub struct CollegeQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::CollegeQuery<'a>,
+}
+
+impl<'a> CollegeQuery<'a> {
+    pub fn get(self) -> Result<College> {
+        self.runtime.block_on(self.inner.get())
+    }
+
+    pub fn campuses(self) -> CampusesQuery<'a> {
+        CampusesQuery {
+            runtime: self.runtime,
+            inner: self.inner.campuses(),
+        }
+    }
+}
+
+pub struct CampusesQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::CampusesQuery<'a>,
+}
+
+impl<'a> CampusesQuery<'a> {
+    pub fn name(mut self, name: &str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    pub fn send(self) -> Result<Vec<Campus>> {
+        self.runtime.block_on(self.inner.send())
+    }
+
+    pub fn campus(self, campus_id: u32) -> CampusQuery<'a> {
+        CampusQuery {
+            runtime: self.runtime,
+            inner: self.inner.campus(campus_id),
+        }
+    }
+}
+
+pub struct CampusQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::CampusQuery<'a>,
+}
+
+impl<'a> CampusQuery<'a> {
+    pub fn get(self) -> Result<Campus> {
+        self.runtime.block_on(self.inner.get())
+    }
+
+    pub fn groups(self) -> GroupsQuery<'a> {
+        GroupsQuery {
+            runtime: self.runtime,
+            inner: self.inner.groups(),
+        }
+    }
+}
+
+pub struct GroupsQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::GroupsQuery<'a>,
+}
+
+impl<'a> GroupsQuery<'a> {
+    pub fn name(mut self, name: &str) -> Self {
+        self.inner = self.inner.name(name);
+        self
+    }
+
+    pub fn send(self) -> Result<Vec<Group>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+pub struct ScheduleQuery<'a> {
+    runtime: &'a Runtime,
+    inner: api::ScheduleQuery<'a>,
+}
+
+impl<'a> ScheduleQuery<'a> {
+    pub fn date(mut self, date: &str) -> Self {
+        self.inner = self.inner.date(date);
+        self
+    }
+
+    pub fn week(mut self, week: Week) -> Self {
+        self.inner = self.inner.week(week);
+        self
+    }
+
+    pub fn today(mut self) -> Self {
+        self.inner = self.inner.today();
+        self
+    }
+
+    pub fn tomorrow(mut self) -> Self {
+        self.inner = self.inner.tomorrow();
+        self
+    }
+
+    pub fn send(self) -> Result<Vec<Schedule>> {
+        self.runtime.block_on(self.inner.send())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[test]
+    fn test_blocking_colleges_send() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body(r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#)
+            .create();
+
+        let client = Client::new(&server.url());
+        let colleges = client.colleges().send().unwrap();
+
+        mock.assert();
+        assert_eq!(colleges[0].name, "Test College");
+    }
+
+    #[test]
+    fn test_blocking_campuses_and_schedule() {
+        let mut server = Server::new();
+        let campuses = server
+            .mock("GET", "/colleges/1/campuses")
+            .with_status(200)
+            .with_body(r#"[{"campusId": 10, "name": "Main", "collegeId": 1}]"#)
+            .create();
+        let schedule = server
+            .mock("GET", "/groups/7/schedules")
+            .match_query(Matcher::UrlEncoded("week".into(), "current".into()))
+            .with_status(200)
+            .with_body(r#"[{"groupId": 7, "date": "2025-11-17", "lessons": []}]"#)
+            .create();
+
+        let client = Client::new(&server.url()).with_college(1);
+        let found = client.campuses().unwrap().send().unwrap();
+        let schedules = client.schedule(7).week(Week::Current).send().unwrap();
+
+        campuses.assert();
+        schedule.assert();
+        assert_eq!(found[0].id, 10);
+        assert_eq!(schedules[0].group_id, 7);
+    }
+
+    #[test]
+    fn test_blocking_college_without_default() {
+        let client = Client::new("https://api.example.com");
+        assert!(client.college().is_err());
+    }
+}
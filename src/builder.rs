@@ -0,0 +1,148 @@
+use crate::{Client, RetryPolicy};
+use std::time::Duration;
+
+/// `User-Agent` of HTTP clients built by this crate.
+pub(crate) const DEFAULT_USER_AGENT: &str = concat!("osars/", env!("CARGO_PKG_VERSION"));
+
+/// A builder for configuring several [`Client`] options at once.
+///
+/// Obtained via [`Client::builder`].
+///
+/// # Examples
+///
+/// ```
+/// use osars::Client;
+/// use std::time::Duration;
+///
+/// let client = Client::builder("https://api.example.com")
+///     .college(1)
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-bot/1.0")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    base_url: String,
+    college_id: Option<u32>,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    http_client: Option<reqwest::Client>,
+}
+
+impl ClientBuilder {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            college_id: None,
+            timeout: None,
+            user_agent: None,
+            http_client: None,
+        }
+    }
+
+    /// Sets the default college ID.
+    pub fn college(mut self, college_id: u32) -> Self {
+        self.college_id = Some(college_id);
+        self
+    }
+
+    /// Sets a timeout for every request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Uses a preconfigured reqwest client.
+    ///
+    /// `timeout` and `user_agent` are applied per request and still take effect.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built, e.g. when the TLS backend
+    /// fails to initialize.
+    pub fn build(self) -> Client {
+        let http_client = self.http_client.unwrap_or_else(|| {
+            http_client_builder()
+                .build()
+                .expect("failed to build HTTP client")
+        });
+
+        Client {
+            base_url: self.base_url,
+            http_client,
+            default_college_id: self.college_id,
+            default_campus_id: None,
+            #[cfg(feature = "lenient-json")]
+            lenient_json: false,
+            limiter: None,
+            timeout: self.timeout,
+            user_agent: self.user_agent,
+            default_headers: Vec::new(),
+            retry: RetryPolicy::default(),
+            rate_limiter: None,
+            cache: None,
+            observer: None,
+            single_flight: None,
+            backend: None,
+        }
+    }
+}
+
+/// Starts a reqwest builder with the crate's defaults and TLS backend.
+pub(crate) fn http_client_builder() -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder().user_agent(DEFAULT_USER_AGENT);
+    #[cfg(feature = "rustls")]
+    let builder = builder.use_rustls_tls();
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rustls")]
+    #[test]
+    fn test_build_with_rustls() {
+        assert!(http_client_builder().build().is_ok());
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let client = Client::builder("https://api.example.com").build();
+        assert_eq!(client.base_url(), "https://api.example.com");
+        assert!(client.default_college_id.is_none());
+    }
+
+    #[test]
+    fn test_builder_configured() {
+        let client = Client::builder("https://api.example.com")
+            .college(42)
+            .timeout(Duration::from_secs(5))
+            .user_agent("osars-test")
+            .build();
+        assert_eq!(client.base_url(), "https://api.example.com");
+        assert_eq!(client.default_college_id, Some(42));
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_builder_with_http_client() {
+        let client = Client::builder("https://api.example.com")
+            .http_client(reqwest::Client::new())
+            .college(7)
+            .build();
+        assert_eq!(client.default_college_id, Some(7));
+    }
+}
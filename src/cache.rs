@@ -0,0 +1,273 @@
+use crate::Client;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Raw JSON bodies of successful GET requests, keyed by request path.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    stored: Instant,
+    body: String,
+    etag: Option<String>,
+}
+
+impl ResponseCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the cached body for `path` unless it has expired.
+    ///
+    /// Expired entries with an ETag are kept for revalidation.
+    pub(crate) fn get(&self, path: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(path) {
+            Some(entry) if entry.stored.elapsed() < self.ttl => Some(entry.body.clone()),
+            Some(entry) if entry.etag.is_none() => {
+                entries.remove(path);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the ETag and body of an entry that can be revalidated.
+    pub(crate) fn validator(&self, path: &str) -> Option<(String, String)> {
+        let entries = self.entries.lock().unwrap();
+        let entry = entries.get(path)?;
+        Some((entry.etag.clone()?, entry.body.clone()))
+    }
+
+    pub(crate) fn insert(&self, path: &str, body: &str, etag: Option<String>) {
+        self.entries.lock().unwrap().insert(
+            path.to_string(),
+            Entry {
+                stored: Instant::now(),
+                body: body.to_string(),
+                etag,
+            },
+        );
+    }
+
+    /// Marks the entry for `path` as fresh again after a 304 response.
+    pub(crate) fn refresh(&self, path: &str) {
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(path) {
+            entry.stored = Instant::now();
+        }
+    }
+
+    /// Drops the entries for `path`, for paths below it and for its ancestors.
+    ///
+    /// Query strings are ignored, so every filtered listing of an affected
+    /// resource is dropped as well.
+    pub(crate) fn invalidate(&self, path: &str) {
+        let target = resource(path);
+        self.entries.lock().unwrap().retain(|key, _| {
+            let key = resource(key);
+            !(key == target || is_under(key, target) || is_under(target, key))
+        });
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// The path of a cache key without its query string or trailing slash.
+fn resource(path: &str) -> &str {
+    path.split('?').next().unwrap_or(path).trim_end_matches('/')
+}
+
+fn is_under(path: &str, parent: &str) -> bool {
+    path.strip_prefix(parent)
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+impl Client {
+    /// Caches GET responses for `ttl`, keyed by request path.
+    ///
+    /// The cache is shared by clones of the client. A successful POST or
+    /// DELETE drops the cached responses for the same path, for paths below
+    /// it and for its ancestors; other entries are kept. Parser updates change
+    /// listings served under other paths, so call
+    /// [`clear_cache`](Self::clear_cache) after them when fresh data matters.
+    ///
+    /// When an expired response carried an `ETag`, the next request sends it
+    /// in `If-None-Match`; a `304 Not Modified` answer reuses the cached body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_cache(Duration::from_secs(300));
+    /// ```
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(Arc::new(ResponseCache::new(ttl)));
+        self
+    }
+
+    /// Drops every cached response.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_second_send_served_from_cache() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body(r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_cache(Duration::from_secs(60));
+        let (_, first) = client.colleges().send_with_meta().await.unwrap();
+        let (colleges, second) = client.colleges().send_with_meta().await.unwrap();
+
+        mock.assert_async().await;
+        assert!(!first.from_cache);
+        assert!(second.from_cache);
+        assert_eq!(colleges[0].name, "Test College");
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_is_refetched() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_cache(Duration::from_millis(50));
+        client.colleges().send().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.colleges().send().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_post_invalidates_related_entries() {
+        let mut server = Server::new_async().await;
+        let listing = server
+            .mock("GET", "/admin/parser")
+            .with_status(200)
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+        let item = server
+            .mock("GET", "/admin/parser/1")
+            .with_status(200)
+            .with_body("{}")
+            .expect(2)
+            .create_async()
+            .await;
+        let _post = server
+            .mock("POST", "/admin/parser")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_cache(Duration::from_secs(60));
+        for _ in 0..2 {
+            let _: serde_json::Value = client.get_json("/admin/parser").await.unwrap();
+            let _: serde_json::Value = client.get_json("/admin/parser/1").await.unwrap();
+            let _: () = client
+                .post_json("/admin/parser", None::<&()>, None)
+                .await
+                .unwrap();
+        }
+
+        listing.assert_async().await;
+        item.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_unrelated_entry_survives_post() {
+        let mut server = Server::new_async().await;
+        let get = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+        let _post = server
+            .mock("POST", "/parser/groups")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_cache(Duration::from_secs(60));
+        client.colleges().send().await.unwrap();
+        client
+            .authenticated()
+            .parser()
+            .update_groups(crate::UpdateGroupsRequest {
+                campus_id: 1,
+                student_group_names: vec![],
+            })
+            .await
+            .unwrap();
+        let (_, meta) = client.colleges().send_with_meta().await.unwrap();
+
+        get.assert_async().await;
+        assert!(meta.from_cache);
+    }
+
+    #[tokio::test]
+    async fn test_etag_revalidation() {
+        let mut server = Server::new_async().await;
+        let not_modified = server
+            .mock("GET", "/colleges")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .create_async()
+            .await;
+        let fresh = server
+            .mock("GET", "/colleges")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("ETag", "\"v1\"")
+            .with_body(r#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_cache(Duration::ZERO);
+        let (_, first) = client.colleges().send_with_meta().await.unwrap();
+        let (colleges, second) = client.colleges().send_with_meta().await.unwrap();
+
+        fresh.assert_async().await;
+        not_modified.assert_async().await;
+        assert!(!first.from_cache);
+        assert_eq!(second.status, 304);
+        assert!(second.from_cache);
+        assert_eq!(colleges[0].name, "Test College");
+    }
+}
@@ -1,8 +1,18 @@
 use crate::Auth;
+use crate::ClientBuilder;
 use crate::api::{CampusQuery, CampusesQuery, CollegeQuery, CollegesQuery};
 use crate::auth::AuthenticatedClient;
+use crate::backend::{Backend, HttpBackend, HttpResponse, to_http_request};
+use crate::cache::ResponseCache;
 use crate::error::Result;
-use crate::{GroupsQuery, ScheduleQuery, error::Error};
+use crate::limiter::AdaptiveLimiter;
+use crate::observer::{Observer, RequestObserver};
+use crate::rate_limit::RateLimiter;
+use crate::retry::RetryPolicy;
+use crate::single_flight::SingleFlight;
+use crate::{GroupsQuery, ScheduleQuery, TeacherScheduleQuery, error::Error};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 /// A client for interacting with the educational schedule API.
 ///
 /// The `Client` provides methods to query colleges, campuses, groups, and schedules.
@@ -19,11 +29,45 @@ use crate::{GroupsQuery, ScheduleQuery, error::Error};
 #[cfg(feature = "logging")]
 use tracing::{debug, error};
 
+/// Metadata describing how a response was obtained.
+///
+/// Returned alongside the data by the `send_with_meta` family of methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Time spent sending the request and reading the body
+    pub elapsed: Duration,
+    /// Whether the data was served from the client-side cache
+    pub from_cache: bool,
+}
+
+/// A successful GET response before deserialization.
+#[derive(Debug, Clone)]
+pub(crate) struct RawResponse {
+    pub(crate) body: String,
+    pub(crate) etag: Option<String>,
+    pub(crate) meta: ResponseMeta,
+}
+
 #[derive(Debug, Clone)]
 pub struct Client {
     pub(crate) base_url: String,
     pub http_client: reqwest::Client,
     pub(crate) default_college_id: Option<u32>,
+    pub(crate) default_campus_id: Option<u32>,
+    #[cfg(feature = "lenient-json")]
+    pub(crate) lenient_json: bool,
+    pub(crate) limiter: Option<Arc<AdaptiveLimiter>>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) default_headers: Vec<(String, String)>,
+    pub(crate) retry: RetryPolicy,
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    pub(crate) cache: Option<Arc<ResponseCache>>,
+    pub(crate) observer: Option<Observer>,
+    pub(crate) single_flight: Option<Arc<SingleFlight>>,
+    pub(crate) backend: Option<Backend>,
 }
 
 impl Client {
@@ -32,6 +76,10 @@ impl Client {
     }
     /// Creates a new client with the specified base URL.
     ///
+    /// The URL is not validated; a malformed one only surfaces as an
+    /// `Error::Reqwest` on the first request. Use [`Client::try_new`] to
+    /// check it up front.
+    ///
     /// # Arguments
     ///
     /// * `base_url` - The base URL of the API endpoint
@@ -43,11 +91,57 @@ impl Client {
     /// let client = Client::new("https://api.example.com");
     /// ```
     pub fn new(base_url: &str) -> Self {
-        Self {
-            base_url: base_url.to_string(),
-            http_client: reqwest::Client::new(),
-            default_college_id: None,
+        Self::builder(base_url).build()
+    }
+
+    /// Creates a new client after checking that the base URL is usable.
+    ///
+    /// Unlike [`Client::new`], which accepts any string and only fails on the
+    /// first request, this rejects URLs without a scheme or host up front.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the API endpoint
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if `base_url` is not an absolute URL with a host.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::try_new("https://api.example.com").unwrap();
+    /// assert!(Client::try_new("api.example.com").is_err());
+    /// ```
+    pub fn try_new(base_url: &str) -> Result<Self> {
+        let url = url::Url::parse(base_url)
+            .map_err(|e| Error::Validation(format!("invalid base URL '{}': {}", base_url, e)))?;
+        if url.host_str().is_none_or(str::is_empty) {
+            return Err(Error::Validation(format!(
+                "invalid base URL '{}': missing host",
+                base_url
+            )));
         }
+        Ok(Self::new(base_url))
+    }
+
+    /// Creates a builder for configuring several options at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL of the API endpoint
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::builder("https://api.example.com")
+    ///     .college(1)
+    ///     .build();
+    /// ```
+    pub fn builder(base_url: &str) -> ClientBuilder {
+        ClientBuilder::new(base_url)
     }
 
     /// Creates a new client with a custom HTTP client.
@@ -71,11 +165,7 @@ impl Client {
     /// let client = osarsClient::with_client("https://api.example.com", http_client);
     /// ```
     pub fn with_client(base_url: &str, http_client: reqwest::Client) -> Self {
-        Self {
-            base_url: base_url.to_string(),
-            http_client,
-            default_college_id: None,
-        }
+        Self::builder(base_url).http_client(http_client).build()
     }
 
     // Sets a default college ID for subsequent queries.
@@ -96,6 +186,224 @@ impl Client {
         self
     }
 
+    /// Looks up a college by its exact name and makes it the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Full name of the college
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if no college or more than one college is
+    /// named exactly `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> osars::Result<()> {
+    /// use osars::Client;
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_college_by_name("Tyumen Industrial College")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_college_by_name(mut self, name: &str) -> Result<Self> {
+        let colleges = self.colleges().name(name).send().await?;
+        let mut matches = colleges.iter().filter(|college| college.name == name);
+
+        let college_id = match (matches.next(), matches.next()) {
+            (Some(college), None) => college.college_id,
+            (None, _) => {
+                return Err(Error::Validation(format!("No college named '{}'", name)));
+            }
+            (Some(_), Some(_)) => {
+                return Err(Error::Validation(format!(
+                    "Multiple colleges named '{}'",
+                    name
+                )));
+            }
+        };
+
+        self.default_college_id = Some(college_id);
+        Ok(self)
+    }
+
+    /// Strips trailing commas from response bodies before parsing them.
+    ///
+    /// Works around backends that emit slightly malformed JSON. Only trailing
+    /// commas before `}` or `]` are repaired; any other syntax error still
+    /// fails with `Error::Serialization`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_lenient_json();
+    /// ```
+    #[cfg(feature = "lenient-json")]
+    pub fn with_lenient_json(mut self) -> Self {
+        self.lenient_json = true;
+        self
+    }
+
+    /// Sets a timeout applied to every request.
+    ///
+    /// The timeout is applied per request, so it also works with a client
+    /// supplied through [`Client::with_client`] and replaces only its timeout.
+    /// A request that times out fails with `Error::Reqwest`.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum duration of a single request
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Routes all requests through an HTTP or SOCKS proxy.
+    ///
+    /// The underlying reqwest client is rebuilt with the proxy, replacing one
+    /// passed to [`Client::with_client`]. Timeout, user agent and default
+    /// headers are kept, as they are applied per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The proxy URL, e.g. `http://proxy.school.local:3128`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if the proxy URL is invalid or the HTTP
+    /// client cannot be rebuilt.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_proxy("http://proxy.school.local:3128")
+    ///     .unwrap();
+    /// ```
+    pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+        let proxy = reqwest::Proxy::all(url)
+            .map_err(|e| Error::Validation(format!("invalid proxy URL '{}': {}", url, e)))?;
+        self.http_client = crate::builder::http_client_builder()
+            .proxy(proxy)
+            .build()
+            .map_err(|e| Error::Validation(format!("failed to build HTTP client: {}", e)))?;
+        Ok(self)
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    ///
+    /// Without it, clients created by this crate identify themselves as
+    /// `osars/<version>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_user_agent("schedule-bot/1.0");
+    /// ```
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Adds a header sent with every request.
+    ///
+    /// Can be called repeatedly to add several headers; setting the same name
+    /// again replaces its previous value. Authentication headers are applied
+    /// after these and are never overwritten. An invalid name or value makes
+    /// requests fail with `Error::Reqwest`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_default_header("X-Api-Gateway-Key", "secret");
+    /// ```
+    pub fn with_default_header(mut self, name: &str, value: &str) -> Self {
+        self.default_headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(name));
+        self.default_headers
+            .push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets a default campus ID for campus-scoped queries.
+    ///
+    /// # Arguments
+    ///
+    /// * `campus_id` - The ID of the campus to set as default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_default_campus(10);
+    /// ```
+    pub fn with_default_campus(mut self, campus_id: u32) -> Self {
+        self.default_campus_id = Some(campus_id);
+        self
+    }
+
+    /// Reports every request and response to `observer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::{Client, RequestObserver};
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// struct Printer;
+    ///
+    /// impl RequestObserver for Printer {
+    ///     fn on_response(&self, status: u16, duration: Duration) {
+    ///         println!("{} in {:?}", status, duration);
+    ///     }
+    /// }
+    ///
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_observer(Arc::new(Printer));
+    /// ```
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = Some(Observer(observer));
+        self
+    }
+
+    /// Sends requests through a custom transport instead of `reqwest`.
+    ///
+    /// The backend receives fully prepared requests, including authentication,
+    /// default headers, the `User-Agent`, the timeout and `If-None-Match` for
+    /// cached responses. Retries, rate limiting, caching and observers still
+    /// apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `backend` - The transport to use, e.g. an in-memory fake for tests
+    pub fn with_backend(mut self, backend: Arc<dyn HttpBackend>) -> Self {
+        self.backend = Some(Backend(backend));
+        self
+    }
+
     /// Creates a query to list all colleges.
     ///
     /// # Examples
@@ -105,7 +413,7 @@ impl Client {
     /// let client = Client::new("https://api.example.com");
     /// let colleges_query = client.colleges();
     /// ```
-    pub fn colleges(&self) -> CollegesQuery {
+    pub fn colleges(&self) -> CollegesQuery<'_> {
         CollegesQuery::new(self)
     }
 
@@ -127,7 +435,7 @@ impl Client {
     ///     .with_college(1);
     /// let college_query = client.college().unwrap();
     /// ```
-    pub fn college(&self) -> Result<CollegeQuery> {
+    pub fn college(&self) -> Result<CollegeQuery<'_>> {
         let college_id = self.default_college_id.ok_or_else(|| {
             Error::Validation("No default college set. Use client.with_college() first".into())
         })?;
@@ -143,7 +451,7 @@ impl Client {
     /// # Errors
     ///
     /// Returns `Error::Validation` if no default college is set.
-    pub fn campuses(&self) -> Result<CampusesQuery> {
+    pub fn campuses(&self) -> Result<CampusesQuery<'_>> {
         let college_id = self
             .default_college_id
             .ok_or_else(|| Error::Validation("No default college set".into()))?;
@@ -163,63 +471,195 @@ impl Client {
     /// # Errors
     ///
     /// Returns `Error::Validation` if no default college is set.
-    pub fn campus(&self, campus_id: u32) -> Result<CampusQuery> {
+    pub fn campus(&self, campus_id: u32) -> Result<CampusQuery<'_>> {
         let _ = self.default_college_id.ok_or_else(|| {
             Error::Validation("No default college set. Use client.with_college() first".into())
         })?;
         Ok(CampusQuery::new(self, campus_id))
     }
 
+    /// Creates a query for the default campus.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(CampusQuery)` if a default campus is set, otherwise `Err(Error)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if no default campus is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_default_campus(10);
+    /// let groups_query = client.campus_default().unwrap().groups();
+    /// ```
+    pub fn campus_default(&self) -> Result<CampusQuery<'_>> {
+        let campus_id = self.default_campus_id.ok_or_else(|| {
+            Error::Validation(
+                "No default campus set. Use client.with_default_campus() first".into(),
+            )
+        })?;
+        Ok(CampusQuery::new(self, campus_id))
+    }
+
+    /// Joins `path` onto the base URL with exactly one slash between them.
+    pub(crate) fn build_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    /// Starts a request with the client-wide per-request settings applied.
+    fn request(&self, method: reqwest::Method, url: &str) -> reqwest::RequestBuilder {
+        let mut request = self.http_client.request(method, url);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        // reqwest adds its own User-Agent only when it sends the request, so a
+        // custom backend needs the default spelled out.
+        let user_agent = self.user_agent.as_deref().or(self
+            .backend
+            .as_ref()
+            .map(|_| crate::builder::DEFAULT_USER_AGENT));
+        if let Some(user_agent) = user_agent {
+            request = request.header(reqwest::header::USER_AGENT, user_agent);
+        }
+        for (name, value) in &self.default_headers {
+            request = request.header(name, value);
+        }
+        request
+    }
+
     pub async fn get_json<T>(&self, path: &str) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
+        self.get_json_with_meta(path).await.map(|(data, _)| data)
+    }
+
+    pub(crate) async fn get_json_with_meta<T>(&self, path: &str) -> Result<(T, ResponseMeta)>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if let Some(body) = self.cache.as_ref().and_then(|cache| cache.get(path)) {
+            let started = Instant::now();
+            let data = serde_json::from_str(&body)?;
+            let meta = ResponseMeta {
+                status: 200,
+                elapsed: started.elapsed(),
+                from_cache: true,
+            };
+            return Ok((data, meta));
+        }
+
+        let raw = match &self.single_flight {
+            Some(flight) => {
+                flight
+                    .run(path, || self.retrying(|| self.fetch_raw(path)))
+                    .await?
+            }
+            None => self.retrying(|| self.fetch_raw(path)).await?,
+        };
+
+        let data = serde_json::from_str(&raw.body).map_err(|e| {
+            #[cfg(feature = "logging")]
+            error!("JSON parse error: {}\nRaw body: {}", e, raw.body);
+            crate::error::Error::Serialization(e)
+        })?;
+        if !raw.meta.from_cache
+            && let Some(cache) = &self.cache
+        {
+            cache.insert(path, &raw.body, raw.etag);
+        }
+        Ok((data, raw.meta))
+    }
+
+    /// Performs a GET request and returns the successful body unparsed.
+    async fn fetch_raw(&self, path: &str) -> Result<RawResponse> {
+        let url = self.build_url(path);
         #[cfg(feature = "logging")]
         debug!("GET {}", url);
 
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .await
-            .map_err(crate::error::Error::Reqwest)?;
+        self.wait_for_rate_limit().await;
+
+        let validator = self.cache.as_ref().and_then(|cache| cache.validator(path));
+        let started = Instant::now();
 
-        let status = response.status();
+        let mut request = self.request(reqwest::Method::GET, &url);
+        if let Some((etag, _)) = &validator {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+
+        let response = self.dispatch(request).await?;
+        let retry_after = crate::error::retry_after(&response.headers);
+        let etag = response
+            .headers
+            .get(reqwest::header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
 
         #[cfg(feature = "logging")]
+        debug!("Response headers: {:#?}", response.headers);
+
+        let status = response.status;
+        let raw_body = response.body;
+
+        if status == reqwest::StatusCode::NOT_MODIFIED.as_u16()
+            && let (Some(cache), Some((_, body))) = (&self.cache, validator)
         {
-            let headers = response.headers();
-            debug!("Response headers: {:#?}", headers);
+            cache.refresh(path);
+            return Ok(RawResponse {
+                body,
+                etag: None,
+                meta: ResponseMeta {
+                    status,
+                    elapsed: started.elapsed(),
+                    from_cache: true,
+                },
+            });
         }
 
-        let raw_body = response
-            .text()
-            .await
-            .map_err(crate::error::Error::Reqwest)?;
-
         #[cfg(feature = "logging")]
         {
-            if status.is_success() {
+            if is_success(status) {
                 debug!("Success {}: raw response = {}", status, raw_body);
             } else {
                 error!("API error {}: raw response = {}", status, raw_body);
             }
         }
 
-        if status.is_success() {
-            serde_json::from_str(&raw_body).map_err(|e| {
-                #[cfg(feature = "logging")]
-                error!("JSON parse error: {}\nRaw body: {}", e, raw_body);
-                crate::error::Error::Serialization(e)
+        #[cfg(feature = "lenient-json")]
+        let raw_body = if self.lenient_json {
+            crate::utils::lenient::strip_trailing_commas(&raw_body)
+        } else {
+            raw_body
+        };
+
+        if is_success(status) {
+            Ok(RawResponse {
+                body: raw_body,
+                etag,
+                meta: ResponseMeta {
+                    status,
+                    elapsed: started.elapsed(),
+                    from_cache: false,
+                },
             })
         } else {
-            Err(crate::error::Error::from_response(
-                status.as_u16(),
+            Err(crate::error::Error::from_status(
+                &format!("GET {}", path),
+                status,
+                retry_after,
                 raw_body,
             ))
         }
     }
+
     pub(crate) async fn post_json<T, B>(
         &self,
         path: &str,
@@ -230,11 +670,34 @@ impl Client {
         T: serde::de::DeserializeOwned,
         B: serde::Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
+        let result = self
+            .retrying(|| self.send_post_json(path, body, auth))
+            .await;
+        if result.is_ok()
+            && let Some(cache) = &self.cache
+        {
+            cache.invalidate(path);
+        }
+        result
+    }
+
+    async fn send_post_json<T, B>(
+        &self,
+        path: &str,
+        body: Option<&B>,
+        auth: Option<&Auth>,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let url = self.build_url(path);
         #[cfg(feature = "logging")]
         debug!("POST {}", url);
 
-        let mut request = self.http_client.post(&url);
+        self.wait_for_rate_limit().await;
+
+        let mut request = self.request(reqwest::Method::POST, &url);
 
         if let Some(auth) = auth {
             request = auth.apply_to_request(request);
@@ -244,50 +707,96 @@ impl Client {
             request = request.json(body);
         }
 
-        let response = request.send().await.map_err(crate::error::Error::Reqwest)?;
+        let response = self.dispatch(request).await?;
 
-        self.handle_response(response).await
+        self.handle_response(&format!("POST {}", path), response)
     }
 
     pub(crate) async fn delete_json<T>(&self, path: &str, auth: Option<&Auth>) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
+        let result = self.retrying(|| self.send_delete_json(path, auth)).await;
+        if result.is_ok()
+            && let Some(cache) = &self.cache
+        {
+            cache.invalidate(path);
+        }
+        result
+    }
+
+    async fn send_delete_json<T>(&self, path: &str, auth: Option<&Auth>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let url = self.build_url(path);
         #[cfg(feature = "logging")]
         debug!("DELETE {}", url);
 
-        let mut request = self.http_client.delete(&url);
+        self.wait_for_rate_limit().await;
+
+        let mut request = self.request(reqwest::Method::DELETE, &url);
 
         if let Some(auth) = auth {
             request = auth.apply_to_request(request);
         }
 
-        let response = request.send().await.map_err(crate::error::Error::Reqwest)?;
+        let response = self.dispatch(request).await?;
 
-        self.handle_response(response).await
+        self.handle_response(&format!("DELETE {}", path), response)
     }
 
-    async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T>
+    /// Sends a request through the backend, reporting it to the observer if one is set.
+    ///
+    /// Requests go to the custom backend when one is installed and to the
+    /// `reqwest` client otherwise.
+    async fn dispatch(&self, request: reqwest::RequestBuilder) -> Result<HttpResponse> {
+        let request = to_http_request(request)?;
+        let backend: &dyn HttpBackend = match &self.backend {
+            Some(Backend(backend)) => backend.as_ref(),
+            None => &self.http_client,
+        };
+
+        if let Some(Observer(observer)) = &self.observer {
+            observer.on_request(&request.method, &request.url);
+        }
+        let started = Instant::now();
+        let response = backend.execute(request).await?;
+        if let Some(Observer(observer)) = &self.observer {
+            observer.on_response(response.status, started.elapsed());
+        }
+        Ok(response)
+    }
+
+    fn handle_response<T>(&self, request: &str, response: HttpResponse) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let status = response.status();
-        let raw_body = response
-            .text()
-            .await
-            .map_err(crate::error::Error::Reqwest)?;
+        let retry_after = crate::error::retry_after(&response.headers);
+        self.parse_response(request, response.status, retry_after, response.body)
+    }
 
+    /// Parses a POST or DELETE response body, mapping error statuses to errors.
+    fn parse_response<T>(
+        &self,
+        request: &str,
+        status: u16,
+        retry_after: Option<Duration>,
+        raw_body: String,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
         #[cfg(feature = "logging")]
         {
-            if status.is_success() {
+            if is_success(status) {
                 debug!("Success {}: raw response = {}", status, raw_body);
             } else {
                 error!("API error {}: raw response = {}", status, raw_body);
             }
         }
 
-        if status.is_success() {
+        if is_success(status) {
             if raw_body.is_empty() {
                 // Handle empty response for DELETE and some POST requests
                 serde_json::from_str("null").map_err(|e| {
@@ -303,8 +812,10 @@ impl Client {
                 })
             }
         } else {
-            Err(crate::error::Error::from_response(
-                status.as_u16(),
+            Err(crate::error::Error::from_status(
+                request,
+                status,
+                retry_after,
                 raw_body,
             ))
         }
@@ -315,7 +826,7 @@ impl Client {
     /// # Arguments
     ///
     /// * `campus_id` - The ID of the campus
-    pub fn groups(&self, campus_id: u32) -> GroupsQuery {
+    pub fn groups(&self, campus_id: u32) -> GroupsQuery<'_> {
         GroupsQuery::new(self, campus_id)
     }
 
@@ -324,7 +835,7 @@ impl Client {
     /// # Arguments
     ///
     /// * `group_id` - The ID of the student group
-    pub fn schedule(&self, group_id: u32) -> ScheduleQuery {
+    pub fn schedule(&self, group_id: u32) -> ScheduleQuery<'_> {
         ScheduleQuery::new(self, group_id)
     }
 
@@ -333,7 +844,7 @@ impl Client {
     /// # Arguments
     ///
     /// * `group_id` - The ID of the student group
-    pub fn today(&self, group_id: u32) -> ScheduleQuery {
+    pub fn today(&self, group_id: u32) -> ScheduleQuery<'_> {
         self.schedule(group_id).today()
     }
 
@@ -342,13 +853,49 @@ impl Client {
     /// # Arguments
     ///
     /// * `group_id` - The ID of the student group
-    pub fn tomorrow(&self, group_id: u32) -> ScheduleQuery {
+    pub fn tomorrow(&self, group_id: u32) -> ScheduleQuery<'_> {
         self.schedule(group_id).tomorrow()
     }
+
+    /// Creates a query for a teacher's schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `teacher_name` - The teacher's name as it appears in lessons
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use osars::Client;
+    ///
+    /// # async fn example() -> osars::Result<()> {
+    /// let client = Client::new("https://api.example.com");
+    /// let schedules = client.teacher_schedule("John Smith").today().send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn teacher_schedule(&self, teacher_name: &str) -> TeacherScheduleQuery<'_> {
+        TeacherScheduleQuery::new(self, teacher_name)
+    }
     /// Create an authenticated client for private endpoints
     pub fn authenticated(&self) -> AuthenticatedClient {
         AuthenticatedClient::new(self.clone())
     }
+
+    /// Create an authenticated client with credentials from the environment
+    ///
+    /// See [`Auth::from_env`] for the variables that are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Validation` if no credentials are set.
+    pub fn authenticated_from_env(&self) -> Result<AuthenticatedClient> {
+        Ok(self.authenticated().with_auth(Auth::from_env()?))
+    }
+}
+
+fn is_success(status: u16) -> bool {
+    (200..300).contains(&status)
 }
 
 #[cfg(test)]
@@ -356,6 +903,102 @@ mod tests {
     use super::*;
     use mockito::{self, Server};
 
+    async fn colleges_named(server: &mut Server, body: &str) -> mockito::Mock {
+        server
+            .mock("GET", "/colleges")
+            .match_query(mockito::Matcher::UrlEncoded("name".into(), "Tech".into()))
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_with_proxy_routes_requests() {
+        let mut proxy = Server::new_async().await;
+        let mock = proxy
+            .mock("GET", "/colleges")
+            .match_header("user-agent", "school-bot/1.0")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new("http://api.example.invalid")
+            .with_timeout(Duration::from_secs(5))
+            .with_user_agent("school-bot/1.0")
+            .with_proxy(&proxy.url())
+            .unwrap();
+        let colleges = client.colleges().send().await.unwrap();
+
+        mock.assert_async().await;
+        assert!(colleges.is_empty());
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_with_proxy_invalid_url() {
+        let result = Client::new("https://api.example.com").with_proxy("not a url");
+
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_with_college_by_name_unique_match() {
+        let mut server = Server::new_async().await;
+        let mock = colleges_named(
+            &mut server,
+            r#"[
+                {"collegeId": 1, "name": "Tech", "calls": [], "campuses": []},
+                {"collegeId": 2, "name": "Tech Academy", "calls": [], "campuses": []}
+            ]"#,
+        )
+        .await;
+
+        let client = Client::new(&server.url())
+            .with_college_by_name("Tech")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(client.default_college_id, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_with_college_by_name_no_match() {
+        let mut server = Server::new_async().await;
+        let _mock = colleges_named(
+            &mut server,
+            r#"[{"collegeId": 2, "name": "Tech Academy", "calls": [], "campuses": []}]"#,
+        )
+        .await;
+
+        let result = Client::new(&server.url())
+            .with_college_by_name("Tech")
+            .await;
+
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_with_college_by_name_ambiguous() {
+        let mut server = Server::new_async().await;
+        let _mock = colleges_named(
+            &mut server,
+            r#"[
+                {"collegeId": 1, "name": "Tech", "calls": [], "campuses": []},
+                {"collegeId": 3, "name": "Tech", "calls": [], "campuses": []}
+            ]"#,
+        )
+        .await;
+
+        let result = Client::new(&server.url())
+            .with_college_by_name("Tech")
+            .await;
+
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
     #[test]
     fn test_client_creation() {
         let client = Client::new("https://api.example.com");
@@ -363,6 +1006,27 @@ mod tests {
         assert!(client.default_college_id.is_none());
     }
 
+    #[test]
+    fn test_try_new_valid() {
+        let client = Client::try_new("https://api.example.com/tyumen").unwrap();
+        assert_eq!(client.base_url, "https://api.example.com/tyumen");
+    }
+
+    #[test]
+    fn test_try_new_without_scheme() {
+        let result = Client::try_new("api.example.com");
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
+    #[test]
+    fn test_try_new_empty_or_hostless() {
+        assert!(matches!(Client::try_new(""), Err(Error::Validation(_))));
+        assert!(matches!(
+            Client::try_new("mailto:admin@example.com"),
+            Err(Error::Validation(_))
+        ));
+    }
+
     #[test]
     fn test_client_with_college() {
         let client = Client::new("https://api.example.com").with_college(123);
@@ -394,6 +1058,174 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_campus_default_without_default() {
+        let client = Client::new("https://api.example.com");
+        let result = client.campus_default();
+        assert!(matches!(result, Err(Error::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_campus_default_with_default() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/campuses/10/groups")
+            .with_status(200)
+            .with_body(r#"[{"studentGroupId": 1, "name": "A", "campusId": 10}]"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_default_campus(10);
+        let groups = client
+            .campus_default()
+            .unwrap()
+            .groups()
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(groups[0].campus_id, 10);
+    }
+
+    #[test]
+    fn test_build_url_with_trailing_slash() {
+        let client = Client::new("https://api.example.com/tyumen/");
+        assert_eq!(
+            client.build_url("/colleges"),
+            "https://api.example.com/tyumen/colleges"
+        );
+    }
+
+    #[test]
+    fn test_build_url_without_trailing_slash() {
+        let client = Client::new("https://api.example.com/tyumen");
+        assert_eq!(
+            client.build_url("/colleges"),
+            "https://api.example.com/tyumen/colleges"
+        );
+        assert_eq!(
+            client.build_url("colleges"),
+            "https://api.example.com/tyumen/colleges"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_json_trailing_slash_base_url() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&format!("{}/", server.url()));
+        let result: Vec<serde_json::Value> = client.get_json("/colleges").await.unwrap();
+
+        mock.assert_async().await;
+        assert!(result.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_with_timeout_elapses() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/slow")
+            .with_status(200)
+            .with_body_from_request(|_| {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                b"{}".to_vec()
+            })
+            .create_async()
+            .await;
+
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .unwrap();
+        let client = Client::with_client(&server.url(), http_client)
+            .with_college(3)
+            .with_timeout(std::time::Duration::from_millis(100));
+        let result: Result<serde_json::Value> = client.get_json("/slow").await;
+
+        assert_eq!(client.default_college_id, Some(3));
+        match result {
+            Err(Error::Reqwest(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected timeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_user_agent() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .match_header("user-agent", concat!("osars/", env!("CARGO_PKG_VERSION")))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        client.colleges().send().await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_with_user_agent() {
+        let mut server = Server::new_async().await;
+        let get = server
+            .mock("GET", "/groups/1/schedules")
+            .match_header("user-agent", "schedule-bot/1.0")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        let post = server
+            .mock("POST", "/parser/calls")
+            .match_header("user-agent", "schedule-bot/1.0")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_user_agent("schedule-bot/1.0");
+        client.schedule(1).send().await.unwrap();
+        client
+            .authenticated()
+            .parser()
+            .update_calls(crate::UpdateCallsRequest { calls: vec![] })
+            .await
+            .unwrap();
+
+        get.assert_async().await;
+        post.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_with_default_headers() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges/1")
+            .match_header("x-api-gateway-key", "secret")
+            .match_header("x-tenant", "tyumen")
+            .with_status(200)
+            .with_body(r#"{"collegeId": 1, "name": "Test", "calls": [], "campuses": []}"#)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .with_default_header("X-Api-Gateway-Key", "old")
+            .with_default_header("X-Tenant", "tyumen")
+            .with_default_header("x-api-gateway-key", "secret")
+            .with_college(1);
+        client.college().unwrap().get().await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(client.default_headers.len(), 2);
+    }
+
     #[tokio::test]
     async fn test_get_json_success() {
         let mut server = Server::new_async().await;
@@ -426,6 +1258,93 @@ mod tests {
         let result: Result<serde_json::Value> = client.get_json("/test").await;
 
         mock.assert_async().await;
-        assert!(result.is_err());
+        assert!(
+            matches!(result, Err(Error::NotFound { ref body, .. }) if body.contains("Not found"))
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            r#"GET /test failed with 404: {"error": "Not found"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_json_rate_limited() {
+        let mut server = Server::new_async().await;
+        let _with_header = server
+            .mock("GET", "/busy")
+            .with_status(429)
+            .with_header("Retry-After", "120")
+            .with_body("slow down")
+            .create_async()
+            .await;
+        let _without_header = server
+            .mock("GET", "/busier")
+            .with_status(429)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let with_header: Result<serde_json::Value> = client.get_json("/busy").await;
+        let without_header: Result<serde_json::Value> = client.get_json("/busier").await;
+
+        assert!(matches!(
+            with_header,
+            Err(Error::RateLimited { retry_after: Some(wait), body, .. })
+                if wait == Duration::from_secs(120) && body == "slow down"
+        ));
+        assert!(matches!(
+            without_header,
+            Err(Error::RateLimited {
+                retry_after: None,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_auth_errors() {
+        let mut server = Server::new_async().await;
+        let _unauthorized = server
+            .mock("GET", "/private")
+            .with_status(401)
+            .with_body("token expired")
+            .create_async()
+            .await;
+        let _forbidden = server
+            .mock("GET", "/admin")
+            .with_status(403)
+            .with_body("admins only")
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let unauthorized: Result<serde_json::Value> = client.get_json("/private").await;
+        let forbidden: Result<serde_json::Value> = client.get_json("/admin").await;
+
+        assert!(
+            matches!(unauthorized, Err(Error::Unauthorized { body, .. }) if body == "token expired")
+        );
+        assert!(matches!(forbidden, Err(Error::Forbidden { body, .. }) if body == "admins only"));
+    }
+
+    #[cfg(feature = "lenient-json")]
+    #[tokio::test]
+    async fn test_get_json_lenient_trailing_comma() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/test")
+            .with_status(200)
+            .with_body(r#"{"names": ["a", "b",],}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let strict = Client::new(&server.url());
+        let result: Result<serde_json::Value> = strict.get_json("/test").await;
+        assert!(matches!(result, Err(Error::Serialization(_))));
+
+        let lenient = Client::new(&server.url()).with_lenient_json();
+        let result: serde_json::Value = lenient.get_json("/test").await.unwrap();
+        assert_eq!(result["names"][1], "b");
     }
 }
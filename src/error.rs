@@ -1,3 +1,7 @@
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use serde::Deserialize;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -5,55 +9,200 @@ pub enum Error {
     #[error("HTTP error: {0}")]
     Reqwest(#[from] reqwest::Error),
 
-    #[error("API error {status_code}: {message}")]
-    Api { status_code: u16, message: String },
+    #[error("{request} failed with {status_code}: {message}")]
+    Api {
+        status_code: u16,
+        message: String,
+        request: String,
+        api_error: Option<ApiError>,
+    },
 
     #[error("Serioalization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
-    #[error("Not found: {0}")]
-    NotFound(String),
+    #[error("{request} failed with 404: {body}")]
+    NotFound {
+        body: String,
+        request: String,
+        api_error: Option<ApiError>,
+    },
+
+    #[error("{request} failed with 429 (retry after {retry_after:?}): {body}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        body: String,
+        request: String,
+        api_error: Option<ApiError>,
+    },
+
+    #[error("{request} failed with 401: {body}")]
+    Unauthorized {
+        body: String,
+        request: String,
+        api_error: Option<ApiError>,
+    },
+
+    #[error("{request} failed with 403: {body}")]
+    Forbidden {
+        body: String,
+        request: String,
+        api_error: Option<ApiError>,
+    },
 
     #[error("Validation error: {0}")]
     Validation(String),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Operation cancelled")]
+    Cancelled,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// A structured error body such as `{"error": "Not found", "code": "E_NOGROUP"}`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ApiError {
+    /// Human-readable description
+    #[serde(rename = "error")]
+    pub message: String,
+    /// Machine-readable error code, if the API sent one
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
 impl Error {
-    pub fn from_response(status: u16, body: String) -> Self {
-        if let Ok(error_response) = serde_json::from_str::<serde_json::Value>(&body) {
-            if let Some(message) = error_response.get("error").and_then(|v| v.as_str()) {
-                return Error::Api {
-                    status_code: status,
-                    message: message.to_string(),
-                };
+    /// Returns `true` if repeating the request may succeed.
+    ///
+    /// Connection errors, timeouts, 5xx responses and 429 are retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Reqwest(e) => e.is_connect() || e.is_timeout() || e.is_request(),
+            Error::Api { status_code, .. } => *status_code >= 500,
+            Error::RateLimited { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the HTTP status of the failed response, if there was one.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Error::Reqwest(e) => e.status().map(|status| status.as_u16()),
+            Error::Api { status_code, .. } => Some(*status_code),
+            Error::RateLimited { .. } => Some(429),
+            Error::Unauthorized { .. } => Some(401),
+            Error::Forbidden { .. } => Some(403),
+            Error::NotFound { .. } => Some(404),
+            _ => None,
+        }
+    }
+
+    /// Returns the structured error body, if the response carried one.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::Api { api_error, .. }
+            | Error::NotFound { api_error, .. }
+            | Error::RateLimited { api_error, .. }
+            | Error::Unauthorized { api_error, .. }
+            | Error::Forbidden { api_error, .. } => api_error.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Like [`Error::from_response`], but maps 429 to `RateLimited`.
+    pub(crate) fn from_status(
+        request: &str,
+        status: u16,
+        retry_after: Option<Duration>,
+        body: String,
+    ) -> Self {
+        if status == 429 {
+            Error::RateLimited {
+                retry_after,
+                api_error: serde_json::from_str(&body).ok(),
+                body,
+                request: request.to_string(),
             }
+        } else {
+            Error::from_response(request, status, body)
         }
+    }
 
-        Error::Api {
-            status_code: status,
-            message: body,
+    /// Builds an error from a non-success response.
+    ///
+    /// 401, 403 and 404 map to `Unauthorized`, `Forbidden` and `NotFound`
+    /// carrying the raw body; other statuses become `Api`, using the `error`
+    /// field of a JSON body as the message when present. A body matching
+    /// [`ApiError`] is parsed and kept alongside.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The failed request, e.g. `GET /colleges/99`
+    /// * `status` - The HTTP status code
+    /// * `body` - The raw response body
+    pub fn from_response(request: &str, status: u16, body: String) -> Self {
+        let request = request.to_string();
+        let api_error: Option<ApiError> = serde_json::from_str(&body).ok();
+        match status {
+            401 => Error::Unauthorized {
+                body,
+                request,
+                api_error,
+            },
+            403 => Error::Forbidden {
+                body,
+                request,
+                api_error,
+            },
+            404 => Error::NotFound {
+                body,
+                request,
+                api_error,
+            },
+            _ => Error::Api {
+                status_code: status,
+                message: api_error
+                    .as_ref()
+                    .map_or(body, |api_error| api_error.message.clone()),
+                request,
+                api_error,
+            },
         }
     }
 }
 
+/// Reads the `Retry-After` header, in either delay-seconds or HTTP-date form.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_error_from_response_with_json() {
-        let body = r#"{"error": "Not found"}"#.to_string();
-        let error = Error::from_response(404, body);
+        let body = r#"{"error": "Bad request"}"#.to_string();
+        let error = Error::from_response("GET /test", 400, body);
 
         match error {
             Error::Api {
                 status_code,
                 message,
+                ..
             } => {
-                assert_eq!(status_code, 404);
-                assert_eq!(message, "Not found");
+                assert_eq!(status_code, 400);
+                assert_eq!(message, "Bad request");
             }
             _ => panic!("Expected Api error"),
         }
@@ -62,12 +211,13 @@ mod tests {
     #[test]
     fn test_error_from_response_with_plain_text() {
         let body = "Internal Server Error".to_string();
-        let error = Error::from_response(500, body);
+        let error = Error::from_response("GET /test", 500, body);
 
         match error {
             Error::Api {
                 status_code,
                 message,
+                ..
             } => {
                 assert_eq!(status_code, 500);
                 assert_eq!(message, "Internal Server Error");
@@ -75,4 +225,105 @@ mod tests {
             _ => panic!("Expected Api error"),
         }
     }
+
+    #[test]
+    fn test_error_from_response_client_errors() {
+        let body = || r#"{"error": "nope"}"#.to_string();
+
+        let error = |status| Error::from_response("GET /test", status, body());
+
+        assert!(matches!(error(401), Error::Unauthorized { body: b, .. } if b == body()));
+        assert!(matches!(error(403), Error::Forbidden { body: b, .. } if b == body()));
+        assert!(matches!(error(404), Error::NotFound { body: b, .. } if b == body()));
+    }
+
+    #[test]
+    fn test_structured_error_body() {
+        let body = r#"{"error": "Not found", "code": "E_NOGROUP"}"#.to_string();
+        let error = Error::from_response("GET /groups/99/schedules", 404, body.clone());
+
+        assert_eq!(
+            error.api_error(),
+            Some(&ApiError {
+                message: "Not found".to_string(),
+                code: Some("E_NOGROUP".to_string()),
+            })
+        );
+        assert!(matches!(error, Error::NotFound { body: raw, .. } if raw == body));
+    }
+
+    #[test]
+    fn test_unstructured_error_body() {
+        let error = Error::from_response("GET /test", 502, "<html>Bad Gateway</html>".to_string());
+
+        assert!(error.api_error().is_none());
+        assert!(
+            matches!(error, Error::Api { message, .. } if message == "<html>Bad Gateway</html>")
+        );
+    }
+
+    #[test]
+    fn test_error_display_includes_request() {
+        let error = Error::from_response("GET /colleges/99", 404, r#"{"error":"no"}"#.to_string());
+
+        assert_eq!(
+            error.to_string(),
+            r#"GET /colleges/99 failed with 404: {"error":"no"}"#
+        );
+    }
+
+    #[test]
+    fn test_retry_after_http_date() {
+        let mut headers = HeaderMap::new();
+        let later = Utc::now() + chrono::Duration::seconds(90);
+        headers.insert(
+            RETRY_AFTER,
+            later
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string()
+                .parse()
+                .unwrap(),
+        );
+
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait > Duration::from_secs(85) && wait <= Duration::from_secs(90));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_error_classification() {
+        let api = |status| Error::from_response("GET /test", status, String::new());
+        let rate_limited = Error::from_status("GET /test", 429, None, String::new());
+
+        assert!(api(503).is_retryable());
+        assert!(!api(400).is_retryable());
+        assert!(rate_limited.is_retryable());
+        assert!(!api(401).is_retryable());
+        assert!(!Error::Validation(String::new()).is_retryable());
+
+        assert_eq!(api(503).status_code(), Some(503));
+        assert_eq!(rate_limited.status_code(), Some(429));
+        assert_eq!(api(401).status_code(), Some(401));
+        assert_eq!(api(403).status_code(), Some(403));
+        assert_eq!(api(404).status_code(), Some(404));
+        assert_eq!(Error::Validation(String::new()).status_code(), None);
+    }
+
+    #[tokio::test]
+    async fn test_connection_error_is_retryable() {
+        let error = reqwest::Client::new()
+            .get("http://127.0.0.1:1")
+            .send()
+            .await
+            .map_err(Error::Reqwest)
+            .unwrap_err();
+
+        assert!(error.is_retryable());
+        assert_eq!(error.status_code(), None);
+    }
 }
@@ -1,13 +1,39 @@
+#[cfg(all(feature = "native-tls", feature = "rustls"))]
+compile_error!(
+    "features `native-tls` and `rustls` are mutually exclusive; \
+     use `default-features = false, features = [\"rustls\"]` for rustls"
+);
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("a TLS backend is required: enable either the `native-tls` or `rustls` feature");
+
 pub mod api;
 pub mod auth;
+pub mod backend;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+mod cache;
 pub mod client;
 pub mod error;
+mod limiter;
 pub mod logging;
 pub mod models;
+pub mod observer;
+pub mod presets;
+mod rate_limit;
+pub mod retry;
+mod single_flight;
 pub mod utils;
 
 pub use api::*;
 pub use auth::*;
+pub use backend::{HttpBackend, HttpRequest, HttpResponse};
+pub use builder::ClientBuilder;
 pub use client::*;
-pub use error::{Error, Result};
+pub use error::{ApiError, Error, Result};
 pub use models::*;
+pub use observer::RequestObserver;
+pub use presets::City;
+pub use retry::RetryPolicy;
+pub use tokio_util::sync::CancellationToken;
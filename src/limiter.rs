@@ -0,0 +1,192 @@
+use crate::api::bulk::DEFAULT_CONCURRENCY;
+use crate::{Client, Error, Result};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Concurrency limit for fan-out helpers that adapts to server pressure.
+///
+/// The limit starts at `max`, halves (down to `min`) whenever a request is
+/// throttled with 429 or a 5xx status, and grows by one after each success.
+#[derive(Debug)]
+pub(crate) struct AdaptiveLimiter {
+    min: usize,
+    max: usize,
+    semaphore: Arc<Semaphore>,
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    limit: usize,
+    /// Permits to retire as they are released, after the limit was lowered.
+    debt: usize,
+}
+
+impl AdaptiveLimiter {
+    pub(crate) fn new(min: usize, max: usize) -> Self {
+        let min = min.max(1);
+        let max = max.max(min);
+        Self {
+            min,
+            max,
+            semaphore: Arc::new(Semaphore::new(max)),
+            state: Mutex::new(LimiterState {
+                limit: max,
+                debt: 0,
+            }),
+        }
+    }
+
+    /// The current concurrency limit.
+    #[cfg(test)]
+    pub(crate) fn limit(&self) -> usize {
+        self.state.lock().unwrap().limit
+    }
+
+    async fn acquire(&self) -> OwnedSemaphorePermit {
+        Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("limiter semaphore is never closed")
+    }
+
+    fn release<T>(&self, permit: OwnedSemaphorePermit, result: &Result<T>) {
+        let mut state = self.state.lock().unwrap();
+        match result {
+            Err(error) if is_throttled(error) => {
+                let lowered = (state.limit / 2).max(self.min);
+                state.debt += state.limit - lowered;
+                state.limit = lowered;
+            }
+            Ok(_) if state.limit < self.max => {
+                state.limit += 1;
+                if state.debt > 0 {
+                    state.debt -= 1;
+                } else {
+                    self.semaphore.add_permits(1);
+                }
+            }
+            _ => {}
+        }
+
+        if state.debt > 0 {
+            state.debt -= 1;
+            permit.forget();
+        }
+    }
+}
+
+fn is_throttled(error: &Error) -> bool {
+    matches!(error, Error::RateLimited { .. })
+        || matches!(error, Error::Api { status_code, .. } if *status_code >= 500)
+}
+
+impl Client {
+    /// Lets fan-out helpers adapt their concurrency between `min` and `max`.
+    ///
+    /// Concurrency is halved when the server responds with 429 or 5xx and
+    /// ramps back up by one request per success. Without this, fan-out
+    /// helpers keep a fixed number of requests in flight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_adaptive_concurrency(1, 8);
+    /// ```
+    pub fn with_adaptive_concurrency(mut self, min: usize, max: usize) -> Self {
+        self.limiter = Some(Arc::new(AdaptiveLimiter::new(min, max)));
+        self
+    }
+
+    /// Upper bound of requests a fan-out helper should buffer.
+    pub(crate) fn fan_out_width(&self) -> usize {
+        self.limiter
+            .as_ref()
+            .map_or(DEFAULT_CONCURRENCY, |limiter| limiter.max)
+    }
+
+    /// Runs a request of a fan-out helper under the adaptive limiter, if any.
+    pub(crate) async fn limited<T>(&self, request: impl Future<Output = Result<T>>) -> Result<T> {
+        let Some(limiter) = &self.limiter else {
+            return request.await;
+        };
+        let permit = limiter.acquire().await;
+        let result = request.await;
+        limiter.release(permit, &result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream::{self, StreamExt};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn throttled() -> Result<()> {
+        Err(Error::Api {
+            status_code: 503,
+            message: "Service Unavailable".to_string(),
+            request: "GET /colleges".to_string(),
+            api_error: None,
+        })
+    }
+
+    /// Runs `count` requests through the limiter and returns the peak number in flight.
+    async fn peak_in_flight(client: &Client, count: usize, outcome: fn() -> Result<()>) -> usize {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        stream::iter(0..count)
+            .map(|_| {
+                client.limited(async {
+                    let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    outcome()
+                })
+            })
+            .buffer_unordered(client.fan_out_width())
+            .collect::<Vec<_>>()
+            .await;
+        peak.into_inner()
+    }
+
+    #[tokio::test]
+    async fn test_throttling_reduces_concurrency() {
+        let client = Client::new("https://api.example.com").with_adaptive_concurrency(1, 8);
+        let limiter = client.limiter.clone().unwrap();
+
+        let initial = peak_in_flight(&client, 8, || Ok(())).await;
+        assert_eq!(initial, 8);
+
+        peak_in_flight(&client, 8, throttled).await;
+        assert_eq!(limiter.limit(), 1);
+
+        // The limit ramps up by one per success, so the peak stays well below the start.
+        let reduced = peak_in_flight(&client, 4, || Ok(())).await;
+        assert!(reduced < initial / 2, "peak in flight was {}", reduced);
+        assert!(limiter.limit() > 1);
+    }
+
+    #[test]
+    fn test_limit_ramps_back_to_max() {
+        let limiter = AdaptiveLimiter::new(2, 4);
+        let semaphore = Arc::clone(&limiter.semaphore);
+
+        let permit = semaphore.clone().try_acquire_owned().unwrap();
+        limiter.release(permit, &throttled());
+        assert_eq!(limiter.limit(), 2);
+
+        for _ in 0..5 {
+            let permit = semaphore.clone().try_acquire_owned().unwrap();
+            limiter.release(permit, &Ok(()));
+        }
+        assert_eq!(limiter.limit(), 4);
+        assert_eq!(semaphore.available_permits(), 4);
+    }
+}
@@ -1,6 +1,8 @@
-use chrono::NaiveTime;
+use chrono::{Duration, NaiveTime};
 use serde::{Deserialize, Serialize};
 
+const TIME_FORMAT: &str = "%H:%M";
+
 /// Represents a call (lesson period) in the college schedule.
 ///
 /// Defines the time periods for lessons throughout the day.
@@ -20,3 +22,38 @@ pub struct Call {
     /// Order of the call in the daily schedule
     pub order: u32,
 }
+
+impl Call {
+    /// Returns the length of the lesson period.
+    pub fn duration(&self) -> Duration {
+        self.ends - self.begins
+    }
+
+    /// Formats the period as `"08:30–10:00"`.
+    pub fn time_range(&self) -> String {
+        format!(
+            "{}–{}",
+            self.begins.format(TIME_FORMAT),
+            self.ends.format(TIME_FORMAT)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_and_time_range() {
+        let call = Call {
+            call_id: 1,
+            weekday: 1,
+            begins: NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+            order: 1,
+        };
+
+        assert_eq!(call.duration(), Duration::minutes(90));
+        assert_eq!(call.time_range(), "08:30–10:00");
+    }
+}
@@ -1,5 +1,6 @@
 use super::Group;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a campus (branch) of a college.
 ///
@@ -18,3 +19,9 @@ pub struct Campus {
     #[serde(default)]
     pub groups: Vec<Group>,
 }
+
+impl fmt::Display for Campus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (#{})", self.name, self.id)
+    }
+}
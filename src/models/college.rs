@@ -1,5 +1,7 @@
-use super::{Call, Campus};
+use super::{Call, Campus, Weekday};
+use crate::error::Result;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents an educational institution.
 ///
@@ -16,3 +18,179 @@ pub struct College {
     /// List of campuses belonging to this college
     pub campuses: Vec<Campus>,
 }
+
+impl College {
+    /// Returns the bell times that apply on `weekday`.
+    ///
+    /// Days with their own call set (e.g. a shortened Saturday) use it;
+    /// other days fall back to Monday's calls. The result is sorted by order.
+    pub fn effective_calls(&self, weekday: Weekday) -> Vec<&Call> {
+        let day = weekday.number_from_monday();
+        let mut calls: Vec<&Call> = self.calls.iter().filter(|c| c.weekday == day).collect();
+        if calls.is_empty() {
+            calls = self.calls.iter().filter(|c| c.weekday == 1).collect();
+        }
+        calls.sort_by_key(|c| c.order);
+        calls
+    }
+
+    /// Finds the bell times for the lesson `order` on `weekday`.
+    ///
+    /// Unlike [`College::effective_calls`], there is no fallback to Monday.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekday` - Day of the week (1-7, where 1 is Monday)
+    /// * `order` - Order of the lesson in the daily schedule
+    pub fn call_for(&self, weekday: u8, order: u32) -> Option<&Call> {
+        self.calls
+            .iter()
+            .find(|call| call.weekday == weekday && call.order == order)
+    }
+
+    /// Finds a campus by name, ignoring case.
+    pub fn campus_by_name(&self, name: &str) -> Option<&Campus> {
+        let name = name.to_lowercase();
+        self.campuses
+            .iter()
+            .find(|campus| campus.name.to_lowercase() == name)
+    }
+
+    /// Finds a campus by its ID.
+    pub fn campus_by_id(&self, id: u32) -> Option<&Campus> {
+        self.campuses.iter().find(|campus| campus.id == id)
+    }
+
+    /// Serializes the college to JSON in the API's field naming.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Serialization` if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Restores a college saved with [`College::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Serialization` if `json` is not a valid college.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl fmt::Display for College {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} — {} campuses", self.name, self.campuses.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveTime;
+
+    fn call(weekday: u8, order: u32, begins: (u32, u32), ends: (u32, u32)) -> Call {
+        Call {
+            call_id: u32::from(weekday) * 10 + order,
+            weekday,
+            begins: NaiveTime::from_hms_opt(begins.0, begins.1, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(ends.0, ends.1, 0).unwrap(),
+            order,
+        }
+    }
+
+    fn college() -> College {
+        College {
+            college_id: 1,
+            name: "Test College".to_string(),
+            calls: vec![
+                call(1, 2, (10, 40), (12, 10)),
+                call(1, 1, (9, 0), (10, 30)),
+                call(6, 1, (9, 0), (10, 0)),
+                call(6, 2, (10, 10), (11, 10)),
+            ],
+            campuses: vec![campus(10, "Main Building"), campus(11, "North Campus")],
+        }
+    }
+
+    fn campus(id: u32, name: &str) -> Campus {
+        Campus {
+            id,
+            name: name.to_string(),
+            college_id: 1,
+            groups: vec![],
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let college = college();
+
+        let restored = College::from_json(&college.to_json().unwrap()).unwrap();
+
+        assert_eq!(restored.college_id, 1);
+        assert_eq!(restored.name, college.name);
+        assert_eq!(restored.calls.len(), 4);
+        assert_eq!(restored.calls[3].begins, college.calls[3].begins);
+        assert_eq!(restored.campus_by_id(11).unwrap().name, "North Campus");
+        assert!(College::from_json(r#"{"name": "No ID"}"#).is_err());
+    }
+
+    #[test]
+    fn test_effective_calls_weekday_specific() {
+        let college = college();
+        let calls = college.effective_calls(Weekday::Saturday);
+
+        assert_eq!(calls.len(), 2);
+        assert!(calls.iter().all(|c| c.weekday == 6));
+        assert_eq!(calls[1].ends, NaiveTime::from_hms_opt(11, 10, 0).unwrap());
+    }
+
+    #[test]
+    fn test_effective_calls_fall_back_to_monday() {
+        let college = college();
+        let calls = college.effective_calls(Weekday::Wednesday);
+
+        let orders: Vec<u32> = calls.iter().map(|c| c.order).collect();
+        assert_eq!(orders, [1, 2]);
+        assert!(calls.iter().all(|c| c.weekday == 1));
+    }
+
+    #[test]
+    fn test_campus_by_name_ignores_case() {
+        let college = college();
+
+        assert_eq!(
+            college.campus_by_name("north campus").map(|c| c.id),
+            Some(11)
+        );
+        assert_eq!(
+            college.campus_by_name("MAIN BUILDING").map(|c| c.id),
+            Some(10)
+        );
+        assert!(college.campus_by_name("South Campus").is_none());
+    }
+
+    #[test]
+    fn test_campus_by_id() {
+        let college = college();
+
+        assert_eq!(
+            college.campus_by_id(10).map(|c| c.name.as_str()),
+            Some("Main Building")
+        );
+        assert!(college.campus_by_id(99).is_none());
+    }
+
+    #[test]
+    fn test_call_for() {
+        let college = college();
+
+        let call = college.call_for(1, 2).unwrap();
+        assert_eq!(call.begins, NaiveTime::from_hms_opt(10, 40, 0).unwrap());
+        assert!(college.call_for(3, 2).is_none());
+        assert!(college.call_for(1, 5).is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use super::Schedule;
+use crate::error::Result;
+use std::io::Write;
+
+const HEADER: &str = "date,order,start_time,end_time,title,teacher,cabinet";
+const TIME_FORMAT: &str = "%H:%M";
+
+/// Writes every lesson of `schedules` as a CSV row.
+///
+/// The first row is the header
+/// `date,order,start_time,end_time,title,teacher,cabinet`. Fields containing
+/// commas, quotes or line breaks are quoted.
+///
+/// # Arguments
+///
+/// * `schedules` - The days to export
+/// * `writer` - Destination of the CSV data
+///
+/// # Errors
+///
+/// Returns `Error::Io` if writing fails.
+pub fn schedules_to_csv<W: Write>(schedules: &[Schedule], mut writer: W) -> Result<()> {
+    writeln!(writer, "{}", HEADER)?;
+    for schedule in schedules {
+        for lesson in schedule.sorted_lessons() {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{}",
+                schedule.date.format("%Y-%m-%d"),
+                lesson.order,
+                lesson.start_time.format(TIME_FORMAT),
+                lesson.end_time.format(TIME_FORMAT),
+                quote(&lesson.title),
+                quote(&lesson.teacher),
+                quote(&lesson.cabinet),
+            )?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lesson;
+    use chrono::{NaiveDate, NaiveTime};
+
+    #[test]
+    fn test_schedules_to_csv() {
+        let schedule = Schedule {
+            group_id: 7,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![Lesson {
+                title: "Math".to_string(),
+                cabinet: "101".to_string(),
+                teacher: "Smith, J.".to_string(),
+                order: 1,
+                start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                end_time: NaiveTime::from_hms_opt(10, 30, 0).unwrap(),
+            }],
+        };
+
+        let mut out = Vec::new();
+        schedules_to_csv(&[schedule], &mut out).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "date,order,start_time,end_time,title,teacher,cabinet\n\
+             2025-11-17,1,09:00,10:30,Math,\"Smith, J.\",101\n"
+        );
+    }
+}
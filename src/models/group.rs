@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents a student group.
 ///
@@ -14,3 +15,9 @@ pub struct Group {
     #[serde(rename = "campusId")]
     pub campus_id: u32,
 }
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (#{})", self.name, self.id)
+    }
+}
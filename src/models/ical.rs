@@ -0,0 +1,174 @@
+use super::{Call, Schedule};
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+const LOCAL_FORMAT: &str = "%Y%m%dT%H%M%S";
+/// Maximum length of a content line in octets, excluding the line break.
+const LINE_LIMIT: usize = 75;
+
+impl Schedule {
+    /// Renders the day as an iCalendar (RFC 5545) document.
+    ///
+    /// Each lesson becomes a `VEVENT` with the title as summary, the cabinet
+    /// as location and the teacher in the description. Lesson times are
+    /// written as floating local times (no `Z` suffix), so calendar apps show
+    /// the bell times as-is in the viewer's timezone; only `DTSTAMP` is UTC.
+    /// Lines longer than 75 octets are folded.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    pub fn to_ical(&self, calls: &[Call]) -> String {
+        export_week_ical(std::slice::from_ref(self), calls)
+    }
+}
+
+/// Renders several days as a single iCalendar (RFC 5545) document.
+///
+/// See [`Schedule::to_ical`] for how lessons are mapped to events.
+///
+/// # Arguments
+///
+/// * `schedules` - The days to export, usually a week
+/// * `calls` - The college's call schedule
+pub fn export_week_ical(schedules: &[Schedule], calls: &[Call]) -> String {
+    let stamp = timestamp(Utc::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//osars//osars {}//EN", env!("CARGO_PKG_VERSION")),
+    ];
+
+    for schedule in schedules {
+        for (lesson, begins, ends) in schedule.lesson_times(calls) {
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!(
+                    "UID:{}-{}-{}@osars",
+                    schedule.group_id,
+                    schedule.date.format("%Y%m%d"),
+                    lesson.order
+                ),
+                format!("DTSTAMP:{}", stamp),
+                format!("DTSTART:{}", local(begins)),
+                format!("DTEND:{}", local(ends)),
+                format!("SUMMARY:{}", escape(&lesson.title)),
+                format!("LOCATION:{}", escape(&lesson.cabinet)),
+                format!("DESCRIPTION:{}", escape(&lesson.teacher)),
+                "END:VEVENT".to_string(),
+            ]);
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold(line) + "\r\n").collect()
+}
+
+fn timestamp(time: DateTime<Utc>) -> String {
+    time.format(TIMESTAMP_FORMAT).to_string()
+}
+
+fn local(time: NaiveDateTime) -> String {
+    time.format(LOCAL_FORMAT).to_string()
+}
+
+/// Folds a content line into chunks of at most 75 octets (RFC 5545 §3.1).
+///
+/// Continuation lines start with a single space, which counts towards their
+/// length. Lines are only split between characters, never inside one.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > LINE_LIMIT {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(ch);
+        width += ch.len_utf8();
+    }
+    folded
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lesson;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn lesson(title: &str, order: u32, start: u32, end: u32) -> Lesson {
+        Lesson {
+            title: title.to_string(),
+            cabinet: "101".to_string(),
+            teacher: "Smith, J.".to_string(),
+            order,
+            start_time: NaiveTime::from_hms_opt(start, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end, 30, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_to_ical() {
+        let schedule = Schedule {
+            group_id: 7,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson("Math", 1, 9, 10), lesson("Physics", 2, 11, 12)],
+        };
+
+        let ical = schedule.to_ical(&[]);
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ical.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ical.contains("DTSTART:20251117T090000\r\n"));
+        assert!(ical.contains("DTEND:20251117T103000\r\n"));
+        assert!(ical.contains("SUMMARY:Physics\r\n"));
+        assert!(ical.contains("DESCRIPTION:Smith\\, J.\r\n"));
+    }
+
+    #[test]
+    fn test_to_ical_bell_times_are_floating() {
+        let schedule = Schedule {
+            group_id: 7,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson("Math", 1, 8, 9)],
+        };
+        let bell = Call {
+            call_id: 1,
+            weekday: 1,
+            begins: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(10, 30, 0).unwrap(),
+            order: 1,
+        };
+
+        let ical = schedule.to_ical(&[bell]);
+
+        assert!(ical.contains("DTSTART:20251117T090000\r\n"));
+        assert!(ical.contains("DTEND:20251117T103000\r\n"));
+        let stamp = ical.lines().find(|l| l.starts_with("DTSTAMP:")).unwrap();
+        assert!(stamp.ends_with('Z'));
+    }
+
+    #[test]
+    fn test_to_ical_folds_long_lines() {
+        let title = "Теоретические основы электротехники и электроники".repeat(2);
+        let schedule = Schedule {
+            group_id: 7,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson(&title, 1, 9, 10)],
+        };
+
+        let ical = schedule.to_ical(&[]);
+
+        assert!(ical.split("\r\n").all(|line| line.len() <= 75));
+        let unfolded = ical.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("SUMMARY:{}\r\n", title)));
+    }
+}
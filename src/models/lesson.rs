@@ -1,16 +1,21 @@
-use chrono::NaiveTime;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// Represents a single lesson in a schedule.
 ///
 /// Contains details about a specific class session.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Lesson {
     /// Title or name of the lesson
     pub title: String,
     /// Classroom or cabinet where the lesson takes place
+    #[serde(default, deserialize_with = "crate::utils::null_string::deserialize")]
     pub cabinet: String,
-    /// Name of the teacher
+    /// Name of the teacher; co-taught lessons list several names, see [`Lesson::teachers`]
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::null_string::deserialize_joined"
+    )]
     pub teacher: String,
     /// Order number of the lesson in the daily schedule
     pub order: u32,
@@ -21,3 +26,49 @@ pub struct Lesson {
     #[serde(rename = "endTime", with = "crate::utils::time_serde")]
     pub end_time: NaiveTime,
 }
+
+impl Lesson {
+    /// Returns how long the lesson lasts.
+    ///
+    /// Times are already parsed on deserialization (see `utils::time_serde`),
+    /// so this is `None` only when the end time is not after the start time.
+    pub fn duration(&self) -> Option<Duration> {
+        let duration = self.end_time - self.start_time;
+        (duration > Duration::zero()).then_some(duration)
+    }
+
+    /// Splits the `teacher` field into individual names.
+    ///
+    /// Names are separated by commas or semicolons; an empty field yields
+    /// an empty list.
+    pub fn teachers(&self) -> Vec<&str> {
+        self.teacher
+            .split([',', ';'])
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Checks whether `query` occurs in the title, teacher or cabinet.
+    ///
+    /// The comparison is case-insensitive; an empty query matches every lesson.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        [&self.title, &self.teacher, &self.cabinet]
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+}
+
+/// A lesson bound to absolute start and end instants.
+///
+/// Produced by [`Schedule::timed_lessons`](super::Schedule::timed_lessons).
+#[derive(Debug, Clone)]
+pub struct TimedLesson {
+    /// The underlying lesson
+    pub lesson: Lesson,
+    /// When the lesson starts
+    pub starts_at: DateTime<Utc>,
+    /// When the lesson ends
+    pub ends_at: DateTime<Utc>,
+}
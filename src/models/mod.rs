@@ -1,28 +1,44 @@
 pub mod call;
 pub mod campus;
 pub mod college;
+#[cfg(feature = "csv")]
+pub mod csv;
 pub mod group;
+#[cfg(feature = "ical")]
+pub mod ical;
 pub mod lesson;
+pub mod quality;
 pub mod requests;
 pub mod schedule;
 
 pub use call::Call;
 pub use campus::Campus;
 pub use college::College;
+#[cfg(feature = "csv")]
+pub use csv::schedules_to_csv;
 pub use group::Group;
-pub use lesson::Lesson;
+#[cfg(feature = "ical")]
+pub use ical::export_week_ical;
+pub use lesson::{Lesson, TimedLesson};
+pub use quality::{QualityReport, data_quality};
 pub use requests::*;
-pub use schedule::Schedule;
+pub use schedule::{
+    Conflict, LessonBlock, LessonChange, Schedule, ScheduleDiff, find_conflicts, merge_schedules,
+    room_occupancy, schedule_fingerprint,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Week {
     Previous,
     Current,
     Next,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Weekday {
     Monday,
     Tuesday,
@@ -33,12 +49,57 @@ pub enum Weekday {
     Sunday,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Day {
     Today,
     Tomorrow,
 }
 
+impl Weekday {
+    /// The default weekend, Saturday and Sunday.
+    pub const WEEKEND: [Weekday; 2] = [Weekday::Saturday, Weekday::Sunday];
+
+    /// Converts a `chrono::Weekday` into the crate's weekday.
+    pub fn from_chrono(weekday: chrono::Weekday) -> Self {
+        match weekday {
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+        }
+    }
+
+    /// Converts the weekday into a `chrono::Weekday`.
+    pub fn to_chrono(self) -> chrono::Weekday {
+        match self {
+            Weekday::Monday => chrono::Weekday::Mon,
+            Weekday::Tuesday => chrono::Weekday::Tue,
+            Weekday::Wednesday => chrono::Weekday::Wed,
+            Weekday::Thursday => chrono::Weekday::Thu,
+            Weekday::Friday => chrono::Weekday::Fri,
+            Weekday::Saturday => chrono::Weekday::Sat,
+            Weekday::Sunday => chrono::Weekday::Sun,
+        }
+    }
+
+    /// Day number as used by `Call::weekday`, where 1 is Monday.
+    pub(crate) fn number_from_monday(&self) -> u8 {
+        match self {
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+            Weekday::Sunday => 7,
+        }
+    }
+}
+
 impl fmt::Display for Week {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -75,7 +136,7 @@ impl fmt::Display for Day {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{NaiveDate, NaiveTime};
+    use chrono::NaiveTime;
 
     #[test]
     fn test_week_display() {
@@ -95,10 +156,95 @@ mod tests {
         assert_eq!(Weekday::Sunday.to_string(), "sunday");
     }
 
+    #[test]
+    fn test_week_serde_round_trip() {
+        for (week, name) in [
+            (Week::Previous, "previous"),
+            (Week::Current, "current"),
+            (Week::Next, "next"),
+        ] {
+            let json = serde_json::to_string(&week).unwrap();
+            assert_eq!(json, format!("\"{}\"", name));
+            assert_eq!(serde_json::from_str::<Week>(&json).unwrap(), week);
+        }
+    }
+
+    #[test]
+    fn test_weekday_serde_round_trip() {
+        for (weekday, name) in [
+            (Weekday::Monday, "monday"),
+            (Weekday::Tuesday, "tuesday"),
+            (Weekday::Wednesday, "wednesday"),
+            (Weekday::Thursday, "thursday"),
+            (Weekday::Friday, "friday"),
+            (Weekday::Saturday, "saturday"),
+            (Weekday::Sunday, "sunday"),
+        ] {
+            let json = serde_json::to_string(&weekday).unwrap();
+            assert_eq!(json, format!("\"{}\"", name));
+            assert_eq!(serde_json::from_str::<Weekday>(&json).unwrap(), weekday);
+        }
+    }
+
+    #[test]
+    fn test_weekday_chrono_conversion() {
+        let days = [
+            (Weekday::Monday, chrono::Weekday::Mon),
+            (Weekday::Tuesday, chrono::Weekday::Tue),
+            (Weekday::Wednesday, chrono::Weekday::Wed),
+            (Weekday::Thursday, chrono::Weekday::Thu),
+            (Weekday::Friday, chrono::Weekday::Fri),
+            (Weekday::Saturday, chrono::Weekday::Sat),
+            (Weekday::Sunday, chrono::Weekday::Sun),
+        ];
+
+        for (weekday, chrono_weekday) in days {
+            assert_eq!(weekday.to_chrono(), chrono_weekday);
+            assert_eq!(Weekday::from_chrono(chrono_weekday), weekday);
+            assert_eq!(
+                u32::from(weekday.number_from_monday()),
+                chrono_weekday.number_from_monday()
+            );
+        }
+    }
+
     #[test]
     fn test_day_display() {
         assert_eq!(Day::Today.to_string(), "today");
         assert_eq!(Day::Tomorrow.to_string(), "tomorrow");
+        assert_eq!(
+            serde_json::to_string(&Day::Tomorrow).unwrap(),
+            r#""tomorrow""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Day>(r#""today""#).unwrap(),
+            Day::Today
+        );
+    }
+
+    #[test]
+    fn test_model_display() {
+        let group = Group {
+            id: 42,
+            name: "IS-24-2".to_string(),
+            campus_id: 3,
+        };
+        let campus = Campus {
+            id: 3,
+            name: "Main Building".to_string(),
+            college_id: 1,
+            groups: vec![group.clone()],
+        };
+        let college = College {
+            college_id: 1,
+            name: "Test College".to_string(),
+            calls: vec![],
+            campuses: vec![campus.clone()],
+        };
+
+        assert_eq!(group.to_string(), "IS-24-2 (#42)");
+        assert_eq!(campus.to_string(), "Main Building (#3)");
+        assert_eq!(college.to_string(), "Test College — 1 campuses");
     }
 
     #[test]
@@ -0,0 +1,86 @@
+use super::{Lesson, Schedule};
+
+/// Completeness of lesson data across a set of schedules.
+///
+/// Each ratio is the share of lessons (from `0.0` to `1.0`) that have the
+/// corresponding field filled in. An empty input is reported as fully complete.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityReport {
+    /// Total number of lessons inspected
+    pub total_lessons: usize,
+    /// Share of lessons with a non-empty teacher
+    pub teacher: f64,
+    /// Share of lessons with a non-empty cabinet
+    pub cabinet: f64,
+    /// Share of lessons whose end time is after their start time
+    pub times: f64,
+}
+
+/// Computes per-field completeness ratios for all lessons in `schedules`.
+///
+/// Useful for monitoring the quality of the upstream feed.
+pub fn data_quality(schedules: &[Schedule]) -> QualityReport {
+    let lessons: Vec<&Lesson> = schedules.iter().flat_map(|s| &s.lessons).collect();
+    let total = lessons.len();
+
+    let ratio = |complete: fn(&Lesson) -> bool| {
+        if total == 0 {
+            1.0
+        } else {
+            lessons.iter().filter(|l| complete(l)).count() as f64 / total as f64
+        }
+    };
+
+    QualityReport {
+        total_lessons: total,
+        teacher: ratio(|l| !l.teacher.trim().is_empty()),
+        cabinet: ratio(|l| !l.cabinet.trim().is_empty()),
+        times: ratio(|l| l.end_time > l.start_time),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn lesson(teacher: &str, cabinet: &str, start: (u32, u32), end: (u32, u32)) -> Lesson {
+        Lesson {
+            title: "Math".to_string(),
+            cabinet: cabinet.to_string(),
+            teacher: teacher.to_string(),
+            order: 1,
+            start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_data_quality_ratios() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![
+                lesson("Smith", "101", (9, 0), (10, 30)),
+                lesson("", "102", (10, 40), (12, 10)),
+                lesson("Brown", "", (0, 0), (0, 0)),
+                lesson(" ", "", (13, 0), (14, 30)),
+            ],
+        };
+
+        let report = data_quality(&[schedule]);
+
+        assert_eq!(report.total_lessons, 4);
+        assert_eq!(report.teacher, 0.5);
+        assert_eq!(report.cabinet, 0.5);
+        assert_eq!(report.times, 0.75);
+    }
+
+    #[test]
+    fn test_data_quality_empty() {
+        let report = data_quality(&[]);
+
+        assert_eq!(report.total_lessons, 0);
+        assert_eq!(report.teacher, 1.0);
+    }
+}
@@ -1,6 +1,9 @@
-use super::Lesson;
-use chrono::NaiveDate;
+use super::{Call, Lesson, TimedLesson};
+use crate::error::Result;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Schedule {
@@ -10,3 +13,1074 @@ pub struct Schedule {
     pub date: NaiveDate,
     pub lessons: Vec<Lesson>,
 }
+
+/// Consecutive identical lessons merged into one agenda entry.
+///
+/// A "double period" of the same subject becomes a single block spanning
+/// an order range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LessonBlock {
+    /// Title or name of the lesson
+    pub title: String,
+    /// Classroom or cabinet where the lessons take place
+    pub cabinet: String,
+    /// Name of the teacher
+    pub teacher: String,
+    /// Order of the first lesson in the block
+    pub first_order: u32,
+    /// Order of the last lesson in the block
+    pub last_order: u32,
+    /// Start time of the first lesson
+    pub start_time: NaiveTime,
+    /// End time of the last lesson
+    pub end_time: NaiveTime,
+}
+
+/// A lesson whose title, teacher or cabinet changed between two versions.
+#[derive(Debug, Clone)]
+pub struct LessonChange {
+    /// The lesson as it was
+    pub old: Lesson,
+    /// The lesson as it is now
+    pub new: Lesson,
+}
+
+/// Differences between two versions of a day, matched by lesson order.
+///
+/// Produced by [`Schedule::diff`].
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleDiff {
+    /// Lessons only present in the newer schedule
+    pub added: Vec<Lesson>,
+    /// Lessons only present in the older schedule
+    pub removed: Vec<Lesson>,
+    /// Lessons present in both with a different title, teacher or cabinet
+    pub modified: Vec<LessonChange>,
+}
+
+impl ScheduleDiff {
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// An overlap between lessons of two groups.
+///
+/// Produced by [`find_conflicts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    /// The group listed first in the input
+    pub first_group: u32,
+    /// The group listed second in the input
+    pub second_group: u32,
+    /// Start of the overlapping bell window
+    pub starts_at: DateTime<Utc>,
+    /// End of the overlapping bell window
+    pub ends_at: DateTime<Utc>,
+}
+
+impl Schedule {
+    /// Serializes the day to JSON in the API's field naming.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Serialization` if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Restores a day saved with [`Schedule::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Serialization` if `json` is not a valid schedule.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns lessons whose order has no bell time defined for this day's weekday.
+    ///
+    /// A non-empty result indicates a mismatch between lessons and the
+    /// college's call schedule.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    pub fn orphan_lessons(&self, calls: &[Call]) -> Vec<&Lesson> {
+        let weekday = self.date.weekday().number_from_monday();
+        self.lessons
+            .iter()
+            .filter(|lesson| {
+                !calls
+                    .iter()
+                    .any(|call| u32::from(call.weekday) == weekday && call.order == lesson.order)
+            })
+            .collect()
+    }
+
+    /// Binds every lesson to absolute start and end instants on this date.
+    ///
+    /// Times come from the call matching the lesson's order on this weekday,
+    /// falling back to the lesson's own times. Wall-clock times are local to
+    /// the college and are bound in `tz`. The result is sorted by start time.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    /// * `tz` - The regional timezone of the college
+    pub fn timed_lessons(&self, calls: &[Call], tz: Tz) -> Vec<TimedLesson> {
+        self.lesson_windows(calls, tz)
+            .into_iter()
+            .map(|(lesson, starts_at, ends_at)| TimedLesson {
+                lesson: lesson.clone(),
+                starts_at,
+                ends_at,
+            })
+            .collect()
+    }
+
+    /// Returns the lesson whose bell window contains `now`.
+    ///
+    /// Windows are bound as in [`Schedule::timed_lessons`], in the timezone of
+    /// `now`, and include their start but not their end. Returns `None` during
+    /// breaks and outside school hours.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    /// * `now` - The moment to check in the college's timezone, usually
+    ///   `Utc::now().with_timezone(&tz)`
+    pub fn current_lesson<'a>(&'a self, calls: &[Call], now: DateTime<Tz>) -> Option<&'a Lesson> {
+        self.lesson_windows(calls, now.timezone())
+            .into_iter()
+            .find(|(_, starts_at, ends_at)| *starts_at <= now && now < *ends_at)
+            .map(|(lesson, _, _)| lesson)
+    }
+
+    /// Returns the earliest lesson starting after `now`.
+    ///
+    /// Windows are bound in the timezone of `now`. Returns `None` once the
+    /// last lesson of the day has started.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    /// * `now` - The moment to check in the college's timezone, usually
+    ///   `Utc::now().with_timezone(&tz)`
+    pub fn next_lesson<'a>(&'a self, calls: &[Call], now: DateTime<Tz>) -> Option<&'a Lesson> {
+        self.lesson_windows(calls, now.timezone())
+            .into_iter()
+            .find(|(_, starts_at, _)| *starts_at > now)
+            .map(|(lesson, _, _)| lesson)
+    }
+
+    /// Returns the gaps between consecutive lessons as `(start, end)` pairs.
+    ///
+    /// Windows are bound as in [`Schedule::timed_lessons`]. Back-to-back or
+    /// overlapping lessons produce no gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    /// * `tz` - The regional timezone of the college
+    pub fn free_windows(&self, calls: &[Call], tz: Tz) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let mut gaps = Vec::new();
+        let mut busy_until: Option<DateTime<Utc>> = None;
+        for (_, starts_at, ends_at) in self.lesson_windows(calls, tz) {
+            if let Some(end) = busy_until
+                && end < starts_at
+            {
+                gaps.push((end, starts_at));
+            }
+            busy_until = Some(busy_until.map_or(ends_at, |end| end.max(ends_at)));
+        }
+        gaps
+    }
+
+    /// Lessons paired with their instants in UTC, bound in `tz`, sorted by start.
+    fn lesson_windows(
+        &self,
+        calls: &[Call],
+        tz: Tz,
+    ) -> Vec<(&Lesson, DateTime<Utc>, DateTime<Utc>)> {
+        self.lesson_times(calls)
+            .into_iter()
+            .map(|(lesson, begins, ends)| (lesson, bind(tz, begins), bind(tz, ends)))
+            .collect()
+    }
+
+    /// Lessons paired with their local wall-clock times, sorted by start.
+    pub(crate) fn lesson_times(
+        &self,
+        calls: &[Call],
+    ) -> Vec<(&Lesson, NaiveDateTime, NaiveDateTime)> {
+        let weekday = self.date.weekday().number_from_monday();
+        let mut times: Vec<_> = self
+            .lessons
+            .iter()
+            .map(|lesson| {
+                let (begins, ends) = calls
+                    .iter()
+                    .find(|call| u32::from(call.weekday) == weekday && call.order == lesson.order)
+                    .map(|call| (call.begins, call.ends))
+                    .unwrap_or((lesson.start_time, lesson.end_time));
+                (lesson, self.date.and_time(begins), self.date.and_time(ends))
+            })
+            .collect();
+        times.sort_by_key(|(_, begins, _)| *begins);
+        times
+    }
+
+    /// Renders the day as compact text suitable for pasting into a chat.
+    ///
+    /// The first line is the date, followed by one line per lesson with its
+    /// order, local time range, title, teacher and room.
+    ///
+    /// # Arguments
+    ///
+    /// * `calls` - The college's call schedule
+    pub fn to_summary_text(&self, calls: &[Call]) -> String {
+        let mut text = self.date.format("%A, %d.%m.%Y").to_string();
+        let lessons = self.lesson_times(calls);
+        if lessons.is_empty() {
+            text.push_str("\nNo lessons");
+        }
+        for (lesson, begins, ends) in lessons {
+            text.push_str(&format!(
+                "\n{}. {}-{} {} — {}, room {}",
+                lesson.order,
+                begins.format("%H:%M"),
+                ends.format("%H:%M"),
+                lesson.title,
+                lesson.teacher,
+                lesson.cabinet,
+            ));
+        }
+        text
+    }
+
+    /// Renders the day as a GitHub-flavored Markdown table.
+    ///
+    /// Columns are Order, Time, Subject, Teacher and Room; lessons are sorted
+    /// by order. The time shows a dash when the lesson has no valid time range
+    /// (see [`Lesson::duration`]).
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::from("| Order | Time | Subject | Teacher | Room |\n");
+        table.push_str("|---|---|---|---|---|\n");
+        for lesson in self.sorted_lessons() {
+            let time = match lesson.duration() {
+                Some(_) => format!(
+                    "{}-{}",
+                    lesson.start_time.format("%H:%M"),
+                    lesson.end_time.format("%H:%M")
+                ),
+                None => "-".to_string(),
+            };
+            table.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                lesson.order,
+                time,
+                markdown_cell(&lesson.title),
+                markdown_cell(&lesson.teacher),
+                markdown_cell(&lesson.cabinet),
+            ));
+        }
+        table
+    }
+
+    /// Returns the lessons matching `query`, see [`Lesson::matches`].
+    pub fn search(&self, query: &str) -> Vec<&Lesson> {
+        self.lessons
+            .iter()
+            .filter(|lesson| lesson.matches(query))
+            .collect()
+    }
+
+    /// Returns `true` if the day has no lessons.
+    pub fn is_empty(&self) -> bool {
+        self.lessons.is_empty()
+    }
+
+    /// Returns the number of lessons on this day.
+    pub fn lesson_count(&self) -> usize {
+        self.lessons.len()
+    }
+
+    /// Returns the lesson with the lowest order, if any.
+    pub fn first_lesson(&self) -> Option<&Lesson> {
+        self.lessons.iter().min_by_key(|lesson| lesson.order)
+    }
+
+    /// Returns the lesson with the highest order, if any.
+    pub fn last_lesson(&self) -> Option<&Lesson> {
+        self.lessons.iter().max_by_key(|lesson| lesson.order)
+    }
+
+    /// Returns the lessons sorted ascending by order.
+    ///
+    /// The `lessons` field keeps the order the API returned them in.
+    pub fn sorted_lessons(&self) -> Vec<&Lesson> {
+        let mut lessons: Vec<&Lesson> = self.lessons.iter().collect();
+        lessons.sort_by_key(|lesson| lesson.order);
+        lessons
+    }
+
+    /// Returns the `n`-th lesson (zero-based) when lessons are sorted by order.
+    ///
+    /// Returns `None` if the day has fewer than `n + 1` lessons.
+    pub fn nth_lesson(&self, n: usize) -> Option<&Lesson> {
+        self.sorted_lessons().get(n).copied()
+    }
+
+    /// Merges consecutive lessons with the same title, teacher and cabinet.
+    ///
+    /// Lessons are considered in order; two lessons are merged only when
+    /// their orders are adjacent.
+    pub fn blocks(&self) -> Vec<LessonBlock> {
+        let mut blocks: Vec<LessonBlock> = Vec::new();
+        for lesson in self.sorted_lessons() {
+            if let Some(block) = blocks.last_mut()
+                && block.last_order + 1 == lesson.order
+                && block.title == lesson.title
+                && block.teacher == lesson.teacher
+                && block.cabinet == lesson.cabinet
+            {
+                block.last_order = lesson.order;
+                block.end_time = lesson.end_time;
+                continue;
+            }
+            blocks.push(LessonBlock {
+                title: lesson.title.clone(),
+                cabinet: lesson.cabinet.clone(),
+                teacher: lesson.teacher.clone(),
+                first_order: lesson.order,
+                last_order: lesson.order,
+                start_time: lesson.start_time,
+                end_time: lesson.end_time,
+            });
+        }
+        blocks
+    }
+
+    /// Compares this schedule with a newer version of it.
+    ///
+    /// Lessons are matched by order; each list in the result is sorted by order.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer schedule
+    pub fn diff(&self, other: &Schedule) -> ScheduleDiff {
+        let mut diff = ScheduleDiff::default();
+        for old in self.sorted_lessons() {
+            match other.lessons.iter().find(|new| new.order == old.order) {
+                None => diff.removed.push(old.clone()),
+                Some(new)
+                    if new.title != old.title
+                        || new.teacher != old.teacher
+                        || new.cabinet != old.cabinet =>
+                {
+                    diff.modified.push(LessonChange {
+                        old: old.clone(),
+                        new: new.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for new in other.sorted_lessons() {
+            if !self.lessons.iter().any(|old| old.order == new.order) {
+                diff.added.push(new.clone());
+            }
+        }
+        diff
+    }
+
+    /// Returns midnight of the schedule's date in the given timezone.
+    ///
+    /// Returns `None` if midnight does not exist in `tz` on that date
+    /// (e.g. skipped by a daylight saving transition).
+    ///
+    /// # Arguments
+    ///
+    /// * `tz` - The regional timezone of the college
+    pub fn normalized_date(&self, tz: Tz) -> Option<DateTime<Tz>> {
+        tz.from_local_datetime(&self.date.and_time(NaiveTime::MIN))
+            .earliest()
+    }
+}
+
+impl IntoIterator for Schedule {
+    type Item = Lesson;
+    type IntoIter = std::vec::IntoIter<Lesson>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lessons.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Schedule {
+    type Item = &'a Lesson;
+    type IntoIter = std::slice::Iter<'a, Lesson>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lessons.iter()
+    }
+}
+
+/// Converts a local wall-clock time in `tz` to UTC.
+///
+/// Times skipped by a daylight saving transition use the offset in effect
+/// just before it; repeated times resolve to the earlier instant.
+fn bind(tz: Tz, time: NaiveDateTime) -> DateTime<Utc> {
+    tz.from_local_datetime(&time)
+        .earliest()
+        .map(|instant| instant.with_timezone(&Utc))
+        .unwrap_or_else(|| (time - tz.offset_from_utc_datetime(&time).fix()).and_utc())
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+/// Computes a stable fingerprint of a list of schedules.
+///
+/// The value is a 64-bit FNV-1a hash of the JSON representation, so it stays
+/// the same across runs and can be persisted between polls.
+pub fn schedule_fingerprint(schedules: &[Schedule]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let bytes = serde_json::to_vec(schedules).unwrap_or_default();
+    bytes.iter().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Finds lessons of different groups that overlap in time.
+///
+/// Lessons are bound to bell windows as in [`Schedule::timed_lessons`], and
+/// two lessons conflict when their windows share any time on the same date.
+/// Entries with the same group ID are never compared with each other.
+///
+/// # Arguments
+///
+/// * `schedules` - Group IDs paired with their schedules
+/// * `calls` - The college's call schedule
+/// * `tz` - The college's timezone, in which bell times are read
+///
+/// # Returns
+///
+/// One conflict per overlapping pair of lessons, in input order.
+pub fn find_conflicts(schedules: &[(u32, Schedule)], calls: &[Call], tz: Tz) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+    for (i, (first_group, first)) in schedules.iter().enumerate() {
+        for (second_group, second) in &schedules[i + 1..] {
+            if first_group == second_group || first.date != second.date {
+                continue;
+            }
+            for (_, first_start, first_end) in first.lesson_windows(calls, tz) {
+                for (_, second_start, second_end) in second.lesson_windows(calls, tz) {
+                    let starts_at = first_start.max(second_start);
+                    let ends_at = first_end.min(second_end);
+                    if starts_at < ends_at {
+                        conflicts.push(Conflict {
+                            first_group: *first_group,
+                            second_group: *second_group,
+                            starts_at,
+                            ends_at,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    conflicts
+}
+
+/// Combines schedules of several groups into one schedule per date.
+///
+/// Lessons of the same date are concatenated, sorted by order and
+/// de-duplicated when identical in every field. Each merged schedule keeps
+/// the group ID of the first input schedule for its date.
+///
+/// # Arguments
+///
+/// * `schedules` - The schedules to merge, in any order
+///
+/// # Returns
+///
+/// One schedule per distinct date, sorted by date.
+pub fn merge_schedules(schedules: &[Schedule]) -> Vec<Schedule> {
+    let mut by_date: BTreeMap<NaiveDate, Schedule> = BTreeMap::new();
+    for schedule in schedules {
+        by_date
+            .entry(schedule.date)
+            .or_insert_with(|| Schedule {
+                lessons: Vec::new(),
+                ..schedule.clone()
+            })
+            .lessons
+            .extend(schedule.lessons.iter().cloned());
+    }
+
+    by_date
+        .into_values()
+        .map(|mut schedule| {
+            schedule.lessons.sort_by_key(|lesson| lesson.order);
+            let mut unique: Vec<Lesson> = Vec::with_capacity(schedule.lessons.len());
+            for lesson in schedule.lessons {
+                if !unique.contains(&lesson) {
+                    unique.push(lesson);
+                }
+            }
+            schedule.lessons = unique;
+            schedule
+        })
+        .collect()
+}
+
+/// Lists the lessons held in a cabinet across several schedules.
+///
+/// Cabinets are compared case-insensitively, ignoring surrounding whitespace.
+///
+/// # Arguments
+///
+/// * `schedules` - The schedules to search, e.g. a week for every group
+/// * `cabinet` - The room to look up
+///
+/// # Returns
+///
+/// The matching lessons sorted by date, then by order.
+pub fn room_occupancy<'a>(schedules: &'a [Schedule], cabinet: &str) -> Vec<&'a Lesson> {
+    let cabinet = cabinet.trim().to_lowercase();
+    let mut lessons: Vec<(NaiveDate, &Lesson)> = schedules
+        .iter()
+        .flat_map(|schedule| {
+            schedule
+                .lessons
+                .iter()
+                .map(|lesson| (schedule.date, lesson))
+        })
+        .filter(|(_, lesson)| lesson.cabinet.trim().to_lowercase() == cabinet)
+        .collect();
+    lessons.sort_by_key(|(date, lesson)| (*date, lesson.order));
+    lessons.into_iter().map(|(_, lesson)| lesson).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lesson(order: u32) -> Lesson {
+        Lesson {
+            title: "Math".to_string(),
+            cabinet: "101".to_string(),
+            teacher: "Smith".to_string(),
+            order,
+            start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(10, 30, 0).unwrap(),
+        }
+    }
+
+    fn titled(title: &str, order: u32) -> Lesson {
+        Lesson {
+            title: title.to_string(),
+            ..lesson(order)
+        }
+    }
+
+    fn call(weekday: u8, order: u32) -> Call {
+        Call {
+            call_id: order,
+            weekday,
+            begins: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(10, 30, 0).unwrap(),
+            order,
+        }
+    }
+
+    /// Monday with lessons in periods 1 and 3, and bells for periods 1-3.
+    fn monday_with_bells() -> (Schedule, Vec<Call>) {
+        let bell = |order, begins: (u32, u32), ends: (u32, u32)| Call {
+            begins: NaiveTime::from_hms_opt(begins.0, begins.1, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(ends.0, ends.1, 0).unwrap(),
+            ..call(1, order)
+        };
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![titled("Physics", 3), titled("Math", 1)],
+        };
+        let calls = vec![
+            bell(1, (9, 0), (10, 30)),
+            bell(2, (10, 40), (12, 10)),
+            bell(3, (12, 40), (14, 10)),
+        ];
+        (schedule, calls)
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2025, 11, 17)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[test]
+    fn test_find_conflicts() {
+        let (monday, calls) = monday_with_bells();
+        let elective = Schedule {
+            group_id: 2,
+            lessons: vec![titled("Art", 2), titled("Music", 3)],
+            ..monday.clone()
+        };
+        let tuesday = Schedule {
+            group_id: 3,
+            date: NaiveDate::from_ymd_opt(2025, 11, 18).unwrap(),
+            lessons: vec![titled("Art", 1)],
+        };
+
+        let conflicts =
+            find_conflicts(&[(1, monday), (2, elective), (3, tuesday)], &calls, Tz::UTC);
+
+        assert_eq!(
+            conflicts,
+            [Conflict {
+                first_group: 1,
+                second_group: 2,
+                starts_at: at(12, 40),
+                ends_at: at(14, 10),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_conflicts_in_college_timezone() {
+        let (monday, calls) = monday_with_bells();
+        let elective = Schedule {
+            group_id: 2,
+            lessons: vec![titled("Art", 3)],
+            ..monday.clone()
+        };
+
+        let conflicts = find_conflicts(
+            &[(1, monday), (2, elective)],
+            &calls,
+            chrono_tz::Asia::Yekaterinburg,
+        );
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].starts_at, at(7, 40));
+        assert_eq!(conflicts[0].ends_at, at(9, 10));
+    }
+
+    #[test]
+    fn test_merge_schedules() {
+        let (monday, _) = monday_with_bells();
+        let elective = Schedule {
+            group_id: 2,
+            lessons: vec![titled("Art", 2), titled("Math", 1)],
+            ..monday.clone()
+        };
+
+        let merged = merge_schedules(&[monday, elective]);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].group_id, 1);
+        let titles: Vec<&str> = merged[0].lessons.iter().map(|l| l.title.as_str()).collect();
+        assert_eq!(titles, ["Math", "Art", "Physics"]);
+    }
+
+    #[test]
+    fn test_room_occupancy() {
+        let in_room = |title: &str, order, cabinet: &str| Lesson {
+            cabinet: cabinet.to_string(),
+            ..titled(title, order)
+        };
+        let schedules = [
+            Schedule {
+                group_id: 2,
+                date: NaiveDate::from_ymd_opt(2025, 11, 18).unwrap(),
+                lessons: vec![in_room("Chemistry", 1, "101 ")],
+            },
+            Schedule {
+                group_id: 1,
+                date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+                lessons: vec![
+                    in_room("Physics", 3, "101a"),
+                    in_room("History", 2, "202"),
+                    in_room("Math", 1, "101A"),
+                ],
+            },
+        ];
+
+        let titles: Vec<&str> = room_occupancy(&schedules, " 101a")
+            .iter()
+            .map(|l| l.title.as_str())
+            .collect();
+        assert_eq!(titles, ["Math", "Physics"]);
+        assert_eq!(room_occupancy(&schedules, "101").len(), 1);
+    }
+
+    #[test]
+    fn test_current_lesson() {
+        let (schedule, calls) = monday_with_bells();
+        let current = |hour, minute| {
+            schedule
+                .current_lesson(&calls, at(hour, minute).with_timezone(&Tz::UTC))
+                .map(|l| l.title.as_str())
+        };
+
+        assert_eq!(current(8, 59), None);
+        assert_eq!(current(9, 0), Some("Math"));
+        assert_eq!(current(10, 29), Some("Math"));
+        assert_eq!(current(10, 30), None);
+        assert_eq!(current(11, 0), None);
+        assert_eq!(current(13, 0), Some("Physics"));
+        assert_eq!(current(14, 10), None);
+    }
+
+    #[test]
+    fn test_next_lesson() {
+        let (schedule, calls) = monday_with_bells();
+        let next = |hour, minute| {
+            schedule
+                .next_lesson(&calls, at(hour, minute).with_timezone(&Tz::UTC))
+                .map(|l| l.title.as_str())
+        };
+
+        assert_eq!(next(7, 0), Some("Math"));
+        assert_eq!(next(9, 0), Some("Physics"));
+        assert_eq!(next(12, 39), Some("Physics"));
+        assert_eq!(next(12, 40), None);
+        assert_eq!(next(18, 0), None);
+    }
+
+    #[test]
+    fn test_current_and_next_lesson_in_college_timezone() {
+        let (schedule, calls) = monday_with_bells();
+        let local = |hour, minute| {
+            chrono_tz::Asia::Yekaterinburg
+                .with_ymd_and_hms(2025, 11, 17, hour, minute, 0)
+                .unwrap()
+        };
+
+        let current = schedule.current_lesson(&calls, local(9, 30));
+        assert_eq!(current.map(|l| l.title.as_str()), Some("Math"));
+        assert!(schedule.current_lesson(&calls, local(8, 59)).is_none());
+        assert!(schedule.current_lesson(&calls, local(10, 30)).is_none());
+
+        let next = schedule.next_lesson(&calls, local(9, 30));
+        assert_eq!(next.map(|l| l.title.as_str()), Some("Physics"));
+    }
+
+    #[test]
+    fn test_free_windows() {
+        let (schedule, calls) = monday_with_bells();
+
+        assert_eq!(
+            schedule.free_windows(&calls, Tz::UTC),
+            [(at(10, 30), at(12, 40))]
+        );
+    }
+
+    #[test]
+    fn test_free_windows_back_to_back() {
+        let (mut schedule, mut calls) = monday_with_bells();
+        schedule.lessons.push(titled("Chemistry", 2));
+        calls[1].begins = NaiveTime::from_hms_opt(10, 30, 0).unwrap();
+        calls[1].ends = NaiveTime::from_hms_opt(12, 40, 0).unwrap();
+
+        assert!(schedule.free_windows(&calls, Tz::UTC).is_empty());
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let (schedule, _) = monday_with_bells();
+
+        assert!(schedule.diff(&schedule.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_changes() {
+        let (old, _) = monday_with_bells();
+        let mut new = old.clone();
+        new.lessons.retain(|l| l.order != 3);
+        new.lessons.push(titled("Chemistry", 4));
+        new.lessons[0].cabinet = "202".to_string();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].title, "Chemistry");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].title, "Physics");
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(diff.modified[0].old.cabinet, "101");
+        assert_eq!(diff.modified[0].new.cabinet, "202");
+    }
+
+    #[test]
+    fn test_into_iterator_keeps_source_order() {
+        let (schedule, _) = monday_with_bells();
+
+        let mut titles = Vec::new();
+        for lesson in &schedule {
+            titles.push(lesson.title.clone());
+        }
+        assert_eq!(titles, ["Physics", "Math"]);
+
+        let orders: Vec<u32> = schedule.into_iter().map(|lesson| lesson.order).collect();
+        assert_eq!(orders, [3, 1]);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let (schedule, _) = monday_with_bells();
+
+        let json = schedule.to_json().unwrap();
+        let restored = Schedule::from_json(&json).unwrap();
+
+        assert!(json.contains(r#""date":"2025-11-17""#));
+        assert_eq!(restored.group_id, schedule.group_id);
+        assert_eq!(restored.date, schedule.date);
+        assert_eq!(restored.lessons, schedule.lessons);
+        assert!(Schedule::from_json("[]").is_err());
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let mut broken = titled("A|B", 2);
+        broken.end_time = broken.start_time;
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![broken, lesson(1)],
+        };
+
+        let lines: Vec<String> = schedule.to_markdown().lines().map(String::from).collect();
+
+        assert_eq!(
+            lines,
+            [
+                "| Order | Time | Subject | Teacher | Room |",
+                "|---|---|---|---|---|",
+                "| 1 | 09:00-10:30 | Math | Smith | 101 |",
+                "| 2 | - | A\\|B | Smith | 101 |",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_date_deserializes_to_naive_date() {
+        let json = r#"{"groupId": 7, "date": "2025-11-17", "lessons": []}"#;
+
+        let schedule: Schedule = serde_json::from_str(json).unwrap();
+        assert_eq!(schedule.date.year(), 2025);
+        assert_eq!(schedule.date.month(), 11);
+        assert_eq!(schedule.date.day(), 17);
+
+        let value = serde_json::to_value(&schedule).unwrap();
+        assert_eq!(value["date"], "2025-11-17");
+    }
+
+    #[test]
+    fn test_lesson_count_and_bounds() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson(2), lesson(4), lesson(1)],
+        };
+
+        assert!(!schedule.is_empty());
+        assert_eq!(schedule.lesson_count(), 3);
+        assert_eq!(schedule.first_lesson().map(|l| l.order), Some(1));
+        assert_eq!(schedule.last_lesson().map(|l| l.order), Some(4));
+    }
+
+    #[test]
+    fn test_empty_day() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 22).unwrap(),
+            lessons: vec![],
+        };
+
+        assert!(schedule.is_empty());
+        assert_eq!(schedule.lesson_count(), 0);
+        assert!(schedule.first_lesson().is_none());
+        assert!(schedule.last_lesson().is_none());
+    }
+
+    #[test]
+    fn test_sorted_lessons() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson(3), lesson(1), lesson(2)],
+        };
+
+        let orders: Vec<u32> = schedule.sorted_lessons().iter().map(|l| l.order).collect();
+        assert_eq!(orders, [1, 2, 3]);
+        assert_eq!(schedule.lessons[0].order, 3);
+    }
+
+    #[test]
+    fn test_orphan_lessons_missing_order() {
+        // 2025-11-17 is a Monday
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson(1), lesson(2), lesson(3)],
+        };
+        let calls = [call(1, 1), call(1, 2), call(2, 3)];
+
+        let orphans = schedule.orphan_lessons(&calls);
+
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].order, 3);
+    }
+
+    #[test]
+    fn test_orphan_lessons_all_matched() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 18).unwrap(),
+            lessons: vec![lesson(1), lesson(2)],
+        };
+        let calls = [call(2, 1), call(2, 2)];
+
+        assert!(schedule.orphan_lessons(&calls).is_empty());
+    }
+
+    #[test]
+    fn test_normalized_date_utc_plus_five() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![],
+        };
+
+        let midnight = schedule
+            .normalized_date(chrono_tz::Asia::Yekaterinburg)
+            .unwrap();
+
+        assert_eq!(midnight.to_rfc3339(), "2025-11-17T00:00:00+05:00");
+        assert_eq!(
+            midnight.with_timezone(&Utc).to_rfc3339(),
+            "2025-11-16T19:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_blocks_merge_consecutive_identical() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![titled("Math", 2), titled("Math", 1), titled("Physics", 3)],
+        };
+
+        let blocks = schedule.blocks();
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].title, "Math");
+        assert_eq!((blocks[0].first_order, blocks[0].last_order), (1, 2));
+        assert_eq!(blocks[1].title, "Physics");
+        assert_eq!((blocks[1].first_order, blocks[1].last_order), (3, 3));
+    }
+
+    #[test]
+    fn test_blocks_keep_non_adjacent_and_differing_apart() {
+        let mut other_room = titled("Math", 2);
+        other_room.cabinet = "202".to_string();
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![titled("Math", 1), other_room, titled("Math", 4)],
+        };
+
+        assert_eq!(schedule.blocks().len(), 3);
+    }
+
+    #[test]
+    fn test_nth_lesson_sorted_by_order() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![
+                titled("Chemistry", 3),
+                titled("Math", 1),
+                titled("Physics", 2),
+            ],
+        };
+
+        assert_eq!(schedule.nth_lesson(0).unwrap().title, "Math");
+        assert_eq!(schedule.nth_lesson(2).unwrap().title, "Chemistry");
+        assert!(schedule.nth_lesson(3).is_none());
+    }
+
+    #[test]
+    fn test_timed_lessons_prefer_call_times() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![lesson(2), lesson(1)],
+        };
+        let mut second = call(1, 2);
+        second.begins = NaiveTime::from_hms_opt(10, 40, 0).unwrap();
+        second.ends = NaiveTime::from_hms_opt(12, 10, 0).unwrap();
+
+        let timed = schedule.timed_lessons(&[second], Tz::UTC);
+
+        assert_eq!(timed[0].lesson.order, 1);
+        assert_eq!(timed[0].starts_at.to_rfc3339(), "2025-11-17T09:00:00+00:00");
+        assert_eq!(timed[1].lesson.order, 2);
+        assert_eq!(timed[1].ends_at.to_rfc3339(), "2025-11-17T12:10:00+00:00");
+    }
+
+    #[test]
+    fn test_timed_lessons_bound_in_college_timezone() {
+        let (schedule, calls) = monday_with_bells();
+
+        let timed = schedule.timed_lessons(&calls, chrono_tz::Asia::Yekaterinburg);
+
+        assert_eq!(timed[0].lesson.title, "Math");
+        assert_eq!(timed[0].starts_at.to_rfc3339(), "2025-11-17T04:00:00+00:00");
+        assert_eq!(timed[0].ends_at.to_rfc3339(), "2025-11-17T05:30:00+00:00");
+    }
+
+    #[test]
+    fn test_to_summary_text() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            lessons: vec![titled("Physics", 2), titled("Math", 1)],
+        };
+        let mut second = call(1, 2);
+        second.begins = NaiveTime::from_hms_opt(10, 40, 0).unwrap();
+        second.ends = NaiveTime::from_hms_opt(12, 10, 0).unwrap();
+
+        assert_eq!(
+            schedule.to_summary_text(&[second]),
+            "Monday, 17.11.2025\n\
+             1. 09:00-10:30 Math — Smith, room 101\n\
+             2. 10:40-12:10 Physics — Smith, room 101"
+        );
+    }
+
+    #[test]
+    fn test_to_summary_text_empty_day() {
+        let schedule = Schedule {
+            group_id: 1,
+            date: NaiveDate::from_ymd_opt(2025, 11, 22).unwrap(),
+            lessons: vec![],
+        };
+
+        assert_eq!(
+            schedule.to_summary_text(&[]),
+            "Saturday, 22.11.2025\nNo lessons"
+        );
+    }
+}
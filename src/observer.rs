@@ -0,0 +1,73 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Callbacks invoked around every HTTP request made by a [`Client`](crate::Client).
+///
+/// Useful for custom instrumentation such as metrics counters or tracing
+/// spans. Both methods default to doing nothing. With retries enabled the
+/// callbacks fire once per attempt.
+pub trait RequestObserver: Send + Sync {
+    /// Called right before a request is sent.
+    fn on_request(&self, _method: &reqwest::Method, _url: &str) {}
+
+    /// Called when response headers arrive; `duration` is measured from sending.
+    fn on_response(&self, _status: u16, _duration: Duration) {}
+}
+
+#[derive(Clone)]
+pub(crate) struct Observer(pub(crate) Arc<dyn RequestObserver>);
+
+impl fmt::Debug for Observer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Observer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Client;
+    use mockito::Server;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        requests: Mutex<Vec<String>>,
+        statuses: Mutex<Vec<u16>>,
+    }
+
+    impl RequestObserver for Recorder {
+        fn on_request(&self, method: &reqwest::Method, url: &str) {
+            self.requests
+                .lock()
+                .unwrap()
+                .push(format!("{} {}", method, url));
+        }
+
+        fn on_response(&self, status: u16, _duration: Duration) {
+            self.statuses.lock().unwrap().push(status);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_observer_sees_request_and_response() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let client = Client::new(&server.url()).with_observer(recorder.clone());
+        client.colleges().send().await.unwrap();
+
+        assert_eq!(
+            *recorder.requests.lock().unwrap(),
+            [format!("GET {}/colleges", server.url())]
+        );
+        assert_eq!(*recorder.statuses.lock().unwrap(), [200]);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Client;
+
+/// Known public OpenScheduleAPI deployments.
+///
+/// This is a convenience layer over [`Client::new`] and [`Client::with_college`];
+/// any deployment can still be reached by passing its URL directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum City {
+    /// Tyumen deployment
+    Tyumen,
+}
+
+impl City {
+    /// Base URL of the deployment
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            City::Tyumen => "https://api.thisishyum.ru/schedule_api/tyumen",
+        }
+    }
+
+    /// College used as the default for the deployment, if any
+    pub fn default_college_id(&self) -> Option<u32> {
+        match self {
+            City::Tyumen => Some(1),
+        }
+    }
+}
+
+impl Client {
+    /// Creates a client for a known deployment.
+    ///
+    /// The client points at the city's base URL and uses its default college.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::{City, Client};
+    /// let client = Client::preset(City::Tyumen);
+    /// ```
+    pub fn preset(city: City) -> Self {
+        let client = Client::new(city.base_url());
+        match city.default_college_id() {
+            Some(college_id) => client.with_college(college_id),
+            None => client,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tyumen_preset() {
+        let client = Client::preset(City::Tyumen);
+        assert_eq!(
+            client.base_url(),
+            "https://api.thisishyum.ru/schedule_api/tyumen"
+        );
+        assert_eq!(client.default_college_id, Some(1));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::Client;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Spaces requests evenly, shared by every clone of a client.
+///
+/// A token bucket holding a single token: each request reserves the next free
+/// slot and waits until it arrives, so bursts are never sent.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + self.interval;
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+impl Client {
+    /// Limits this client and all its clones to `requests_per_second`.
+    ///
+    /// Every request waits for its turn before being sent, so concurrent
+    /// tasks share the limit. Passing `0` removes the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    /// let client = Client::new("https://api.example.com")
+    ///     .with_rate_limit(5);
+    /// ```
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limiter =
+            (requests_per_second > 0).then(|| Arc::new(RateLimiter::new(requests_per_second)));
+        self
+    }
+
+    /// Waits until the rate limit allows another request.
+    pub(crate) async fn wait_for_rate_limit(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .expect(5)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_rate_limit(10);
+        let clone = client.clone();
+        let started = std::time::Instant::now();
+        let requests = (0..5).map(|i| {
+            let client = if i % 2 == 0 { &client } else { &clone };
+            client.colleges().send()
+        });
+        for result in future::join_all(requests).await {
+            result.unwrap();
+        }
+
+        mock.assert_async().await;
+        assert!(started.elapsed() >= Duration::from_millis(400));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Client, Result};
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// How transient failures are retried.
+///
+/// Errors for which [`Error::is_retryable`](crate::Error::is_retryable) holds
+/// (connection errors, timeouts, 5xx and 429 responses) are retried with
+/// exponential backoff; other errors fail immediately. The default policy makes a single attempt.
+///
+/// # Examples
+///
+/// ```
+/// use osars::{Client, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = Client::new("https://api.example.com").with_retry(RetryPolicy {
+///     max_attempts: 3,
+///     base_delay: Duration::from_millis(200),
+///     jitter: Duration::from_millis(100),
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further retry
+    pub base_delay: Duration,
+    /// Upper bound of the random delay added to every backoff
+    pub jitter: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(100),
+            jitter: Duration::ZERO,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the given retry, counting from 1.
+    fn backoff(&self, retry: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let jitter_nanos = self.jitter.as_nanos() as u64;
+        let jitter = if jitter_nanos == 0 {
+            Duration::ZERO
+        } else {
+            let random = RandomState::new().build_hasher().finish();
+            Duration::from_nanos(random % jitter_nanos)
+        };
+        exponential + jitter
+    }
+}
+
+impl Client {
+    /// Retries transient failures according to `policy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The retry policy for all requests of this client
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Runs `attempt` until it succeeds, fails permanently or runs out of attempts.
+    pub(crate) async fn retrying<T, F, Fut>(&self, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retry = 0;
+        loop {
+            match attempt().await {
+                Err(error) if error.is_retryable() && retry + 1 < self.retry.max_attempts => {
+                    retry += 1;
+                    tokio::time::sleep(self.retry.backoff(retry)).await;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            jitter: Duration::from_millis(1),
+        }
+    }
+
+    #[test]
+    fn test_backoff_is_exponential() {
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(100),
+            jitter: Duration::ZERO,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let mut server = Server::new_async().await;
+        let failing = server
+            .mock("GET", "/colleges")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body("[]")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_retry(policy(3));
+        let colleges = client.colleges().send().await.unwrap();
+
+        failing.assert_async().await;
+        ok.assert_async().await;
+        assert!(colleges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_client_error_is_not_retried() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges/1")
+            .with_status(404)
+            .with_body(r#"{"error": "Not found"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url())
+            .with_college(1)
+            .with_retry(policy(3));
+        let result = client.college().unwrap().get().await;
+
+        mock.assert_async().await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_default_policy_makes_single_attempt() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(502)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url());
+        let result = client.colleges().send().await;
+
+        mock.assert_async().await;
+        assert!(result.is_err());
+    }
+}
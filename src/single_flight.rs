@@ -0,0 +1,131 @@
+use crate::client::RawResponse;
+use crate::{Client, Result};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+
+/// Registry of GET requests currently in flight, keyed by request path.
+///
+/// The first caller for a path performs the request; callers arriving while
+/// it runs wait for its body instead of sending their own. If the first
+/// request fails or is cancelled, waiting callers send their own request.
+#[derive(Debug, Default)]
+pub(crate) struct SingleFlight {
+    calls: Mutex<HashMap<String, broadcast::Sender<RawResponse>>>,
+}
+
+impl SingleFlight {
+    pub(crate) async fn run<F, Fut>(&self, key: &str, fetch: F) -> Result<RawResponse>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<RawResponse>>,
+    {
+        let waiting = {
+            let mut calls = self.calls.lock().unwrap();
+            match calls.get(key) {
+                Some(sender) => Some(sender.subscribe()),
+                None => {
+                    calls.insert(key.to_string(), broadcast::channel(1).0);
+                    None
+                }
+            }
+        };
+
+        if let Some(mut receiver) = waiting {
+            return match receiver.recv().await {
+                Ok(raw) => Ok(raw),
+                Err(_) => fetch().await,
+            };
+        }
+
+        let mut leader = Leader {
+            flight: self,
+            key,
+            done: false,
+        };
+        let result = fetch().await;
+        if let Some(sender) = leader.finish()
+            && let Ok(raw) = &result
+        {
+            let _ = sender.send(raw.clone());
+        }
+        result
+    }
+}
+
+/// Removes the registry entry even if the leading request is cancelled.
+struct Leader<'a> {
+    flight: &'a SingleFlight,
+    key: &'a str,
+    done: bool,
+}
+
+impl Leader<'_> {
+    fn finish(&mut self) -> Option<broadcast::Sender<RawResponse>> {
+        self.done = true;
+        self.flight.calls.lock().unwrap().remove(self.key)
+    }
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.finish();
+        }
+    }
+}
+
+impl Client {
+    /// Shares one request between concurrent identical GET calls.
+    ///
+    /// While a GET for a path is in flight, further calls for the same path
+    /// wait for its response instead of hitting the server again. Shared by
+    /// clones of the client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use osars::Client;
+    ///
+    /// let client = Client::new("https://api.example.com").with_single_flight();
+    /// ```
+    pub fn with_single_flight(mut self) -> Self {
+        self.single_flight = Some(Arc::new(SingleFlight::default()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future;
+    use mockito::Server;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_identical_requests_share_one_call() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/colleges")
+            .with_status(200)
+            .with_body_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(200));
+                br#"[{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}]"#
+                    .to_vec()
+            })
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Client::new(&server.url()).with_single_flight();
+        let results = future::join_all((0..5).map(|_| client.colleges().send())).await;
+
+        mock.assert_async().await;
+        assert!(
+            results
+                .iter()
+                .all(|colleges| colleges.as_ref().unwrap()[0].name == "Test College")
+        );
+    }
+}
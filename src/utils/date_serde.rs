@@ -1,5 +1,5 @@
 use chrono::{DateTime, NaiveDate};
-use serde::{Deserializer, Serializer, de};
+use serde::{Deserialize, Deserializer, Serializer, de};
 
 pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -8,16 +8,66 @@ where
     serializer.serialize_str(&date.format("%Y-%m-%d").to_string())
 }
 
+/// Parses a bare `YYYY-MM-DD` date or a full RFC 3339 timestamp.
+///
+/// Timestamps keep the calendar date of their own offset. Serialization
+/// always writes the bare date.
 pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = <&str as de::Deserialize<'de>>::deserialize(deserializer)?;
+    let s = String::deserialize(deserializer)?;
 
-    if s.contains('T') {
-        let dt = DateTime::parse_from_rfc3339(s).map_err(de::Error::custom)?;
-        Ok(dt.date_naive())
-    } else {
-        NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(de::Error::custom)
+    match DateTime::parse_from_rfc3339(&s) {
+        Ok(dt) => Ok(dt.date_naive()),
+        Err(_) => NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Dated {
+        #[serde(with = "super")]
+        date: chrono::NaiveDate,
+    }
+
+    fn parse(date: &str) -> serde_json::Result<Dated> {
+        serde_json::from_str(&format!(r#"{{"date": "{}"}}"#, date))
+    }
+
+    #[test]
+    fn test_deserialize_date() {
+        let dated = parse("2025-11-15").unwrap();
+
+        assert_eq!(dated.date.to_string(), "2025-11-15");
+    }
+
+    #[test]
+    fn test_deserialize_rfc3339_timestamp() {
+        assert_eq!(
+            parse("2025-11-15T08:30:00Z").unwrap().date.to_string(),
+            "2025-11-15"
+        );
+        assert_eq!(
+            parse("2025-11-15 23:30:00-02:00").unwrap().date.to_string(),
+            "2025-11-15"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_from_value() {
+        let value = serde_json::json!({"date": "2025-11-15"});
+
+        let dated: Dated = serde_json::from_value(value).unwrap();
+
+        assert_eq!(dated.date.to_string(), "2025-11-15");
+    }
+
+    #[test]
+    fn test_deserialize_rejects_invalid_date() {
+        assert!(parse("2025-13-40").is_err());
     }
 }
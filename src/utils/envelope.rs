@@ -0,0 +1,75 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// A list response, optionally wrapped as `{ "data": [...], "meta": {...} }`.
+///
+/// Deserializes from both the enveloped form and a bare JSON array; in the
+/// latter case `meta` is `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "RawEnvelope<T>")]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct Envelope<T> {
+    /// The unwrapped list items
+    pub data: Vec<T>,
+    /// Metadata sent alongside the items, if any
+    pub meta: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+enum RawEnvelope<T> {
+    Enveloped {
+        data: Vec<T>,
+        #[serde(default)]
+        meta: Option<serde_json::Value>,
+    },
+    Bare(Vec<T>),
+}
+
+impl<T> From<RawEnvelope<T>> for Envelope<T> {
+    fn from(raw: RawEnvelope<T>) -> Self {
+        match raw {
+            RawEnvelope::Enveloped { data, meta } => Self { data, meta },
+            RawEnvelope::Bare(data) => Self { data, meta: None },
+        }
+    }
+}
+
+/// Parses a list response that may or may not be wrapped in a data envelope.
+pub fn deserialize_envelope<T>(body: &str) -> serde_json::Result<Envelope<T>>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_str(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::College;
+
+    #[test]
+    fn test_deserialize_enveloped() {
+        let body = r#"{
+            "data": [{"collegeId": 1, "name": "Test College", "calls": [], "campuses": []}],
+            "meta": {"total": 1}
+        }"#;
+
+        let envelope: Envelope<College> = deserialize_envelope(body).unwrap();
+
+        assert_eq!(envelope.data.len(), 1);
+        assert_eq!(envelope.data[0].college_id, 1);
+        assert_eq!(envelope.meta.unwrap()["total"], 1);
+    }
+
+    #[test]
+    fn test_deserialize_bare_array() {
+        let body = r#"[{"collegeId": 2, "name": "Other", "calls": [], "campuses": []}]"#;
+
+        let envelope: Envelope<College> = deserialize_envelope(body).unwrap();
+
+        assert_eq!(envelope.data[0].college_id, 2);
+        assert!(envelope.meta.is_none());
+    }
+}
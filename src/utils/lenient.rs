@@ -0,0 +1,54 @@
+/// Removes trailing commas before a closing `}` or `]`.
+///
+/// Commas inside string literals are left untouched. This is the only repair
+/// performed: comments, single-quoted strings, unquoted keys and other JSON5
+/// extensions are still rejected by the parser.
+pub fn strip_trailing_commas(body: &str) -> String {
+    let mut out = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            ',' => {
+                let rest = chars.clone().find(|c| !c.is_whitespace());
+                if !matches!(rest, Some('}') | Some(']')) {
+                    out.push(c);
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_trailing_commas() {
+        let body = r#"{"a": [1, 2, ], "b": "x,]", }"#;
+        assert_eq!(
+            strip_trailing_commas(body),
+            r#"{"a": [1, 2 ], "b": "x,]" }"#
+        );
+    }
+}
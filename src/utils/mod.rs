@@ -1,2 +1,8 @@
 pub mod date_serde;
+pub mod envelope;
+#[cfg(feature = "lenient-json")]
+pub mod lenient;
+pub mod null_string;
 pub mod time_serde;
+
+pub use envelope::{Envelope, deserialize_envelope};
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a string, treating `null` as an empty string.
+///
+/// Combine with `#[serde(default)]` to also accept a missing field.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Like [`deserialize`], but also accepts an array of strings joined with `", "`.
+pub fn deserialize_joined<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<StringOrList>::deserialize(deserializer)? {
+        Some(StringOrList::One(value)) => value,
+        Some(StringOrList::Many(values)) => values.join(", "),
+        None => String::new(),
+    })
+}
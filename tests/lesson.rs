@@ -1,5 +1,6 @@
 // tests/lesson.rs
-use osars::models::Lesson;
+use chrono::{Duration, NaiveDate, NaiveTime};
+use osars::models::{Lesson, Schedule};
 
 #[test]
 fn parse_camel_case_time() {
@@ -16,3 +17,112 @@ fn parse_camel_case_time() {
     assert_eq!(lesson.start_time.to_string(), "09:00:00");
     assert_eq!(lesson.end_time.to_string(), "10:30:00");
 }
+
+#[test]
+fn missing_or_null_teacher_and_cabinet_are_empty() {
+    let missing = r#"{"title":"Math","order":1,"startTime":"09:00:00","endTime":"10:30:00"}"#;
+    let null = r#"{"title":"Math","cabinet":null,"teacher":null,"order":1,"startTime":"09:00:00","endTime":"10:30:00"}"#;
+    let present = r#"{"title":"Math","cabinet":"101","teacher":"John","order":1,"startTime":"09:00:00","endTime":"10:30:00"}"#;
+
+    for json in [missing, null] {
+        let lesson: Lesson = serde_json::from_str(json).unwrap();
+        assert_eq!(lesson.teacher, "");
+        assert_eq!(lesson.cabinet, "");
+    }
+
+    let lesson: Lesson = serde_json::from_str(present).unwrap();
+    assert_eq!(lesson.teacher, "John");
+    assert_eq!(lesson.cabinet, "101");
+}
+
+#[test]
+fn teachers_split_on_commas_and_semicolons() {
+    assert_eq!(
+        lesson("Math", "Ivanova A.", "101").teachers(),
+        ["Ivanova A."]
+    );
+    assert_eq!(
+        lesson("Math", "Ivanova A., Petrov B.", "101").teachers(),
+        ["Ivanova A.", "Petrov B."]
+    );
+    assert_eq!(
+        lesson("Math", "Ivanova A.;Petrov B.", "101").teachers(),
+        ["Ivanova A.", "Petrov B."]
+    );
+    assert!(lesson("Math", "", "101").teachers().is_empty());
+}
+
+#[test]
+fn teacher_array_is_joined() {
+    let json = r#"{"title":"Math","cabinet":"101","teacher":["Ivanova A.","Petrov B."],"order":1,"startTime":"09:00:00","endTime":"10:30:00"}"#;
+
+    let lesson: Lesson = serde_json::from_str(json).unwrap();
+    assert_eq!(lesson.teacher, "Ivanova A., Petrov B.");
+    assert_eq!(lesson.teachers(), ["Ivanova A.", "Petrov B."]);
+}
+
+#[test]
+fn lesson_times_round_trip() {
+    let json = r#"{"title":"Math","cabinet":"101","teacher":"John","order":2,"startTime":"10:40:00","endTime":"12:10:00"}"#;
+
+    let lesson: Lesson = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        lesson.start_time,
+        NaiveTime::from_hms_opt(10, 40, 0).unwrap()
+    );
+    assert_eq!(lesson.end_time, NaiveTime::from_hms_opt(12, 10, 0).unwrap());
+
+    let value = serde_json::to_value(&lesson).unwrap();
+    assert_eq!(value["startTime"], "10:40:00");
+    assert_eq!(value["endTime"], "12:10:00");
+}
+
+#[test]
+fn duration_of_regular_lesson() {
+    let lesson = lesson("Math", "John", "101");
+    assert_eq!(lesson.duration(), Some(Duration::minutes(90)));
+}
+
+#[test]
+fn duration_is_none_for_inverted_times() {
+    let mut lesson = lesson("Math", "John", "101");
+    lesson.end_time = lesson.start_time;
+    assert_eq!(lesson.duration(), None);
+
+    lesson.end_time = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+    assert_eq!(lesson.duration(), None);
+}
+
+fn lesson(title: &str, teacher: &str, cabinet: &str) -> Lesson {
+    Lesson {
+        title: title.to_string(),
+        cabinet: cabinet.to_string(),
+        teacher: teacher.to_string(),
+        order: 1,
+        start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        end_time: NaiveTime::from_hms_opt(10, 30, 0).unwrap(),
+    }
+}
+
+#[test]
+fn search_by_teacher_and_room_fragment() {
+    let schedule = Schedule {
+        group_id: 1,
+        date: NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+        lessons: vec![
+            lesson("Math", "Ivanova A.", "101"),
+            lesson("Physics", "Petrov B.", "214"),
+        ],
+    };
+
+    let by_teacher = schedule.search("IVANOV");
+    assert_eq!(by_teacher.len(), 1);
+    assert_eq!(by_teacher[0].title, "Math");
+
+    let by_room = schedule.search("21");
+    assert_eq!(by_room.len(), 1);
+    assert_eq!(by_room[0].title, "Physics");
+
+    assert_eq!(schedule.search("").len(), 2);
+    assert!(!lesson("Math", "Ivanova A.", "101").matches("chemistry"));
+}